@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day01::Day01, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day01.parse(input);
+    for part in 1..=Day01.parts() {
+        let _ = Day01.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day02::Day02, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day02.parse(input);
+    for part in 1..=Day02.parts() {
+        let _ = Day02.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day03::Day03, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day03.parse(input);
+    for part in 1..=Day03.parts() {
+        let _ = Day03.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day04::Day04, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day04.parse(input);
+    for part in 1..=Day04.parts() {
+        let _ = Day04.solve_part(part, input, &params);
+    }
+});
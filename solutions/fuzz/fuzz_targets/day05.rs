@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day05::Day05, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day05.parse(input);
+    for part in 1..=Day05.parts() {
+        let _ = Day05.solve_part(part, input, &params);
+    }
+});
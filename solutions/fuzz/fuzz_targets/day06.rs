@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day06::Day06, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day06.parse(input);
+    for part in 1..=Day06.parts() {
+        let _ = Day06.solve_part(part, input, &params);
+    }
+});
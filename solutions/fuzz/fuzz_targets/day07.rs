@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day07::Day07, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day07.parse(input);
+    for part in 1..=Day07.parts() {
+        let _ = Day07.solve_part(part, input, &params);
+    }
+});
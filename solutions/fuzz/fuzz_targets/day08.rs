@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day08::Day08, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day08.parse(input);
+    for part in 1..=Day08.parts() {
+        let _ = Day08.solve_part(part, input, &params);
+    }
+});
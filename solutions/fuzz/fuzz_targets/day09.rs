@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day09::Day09, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day09.parse(input);
+    for part in 1..=Day09.parts() {
+        let _ = Day09.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day10::Day10, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day10.parse(input);
+    for part in 1..=Day10.parts() {
+        let _ = Day10.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day11::Day11, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day11.parse(input);
+    for part in 1..=Day11.parts() {
+        let _ = Day11.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day12::Day12, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day12.parse(input);
+    for part in 1..=Day12.parts() {
+        let _ = Day12.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day13::Day13, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day13.parse(input);
+    for part in 1..=Day13.parts() {
+        let _ = Day13.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day14::Day14, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day14.parse(input);
+    for part in 1..=Day14.parts() {
+        let _ = Day14.solve_part(part, input, &params);
+    }
+});
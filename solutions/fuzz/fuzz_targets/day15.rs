@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day15::Day15, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day15.parse(input);
+    for part in 1..=Day15.parts() {
+        let _ = Day15.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day16::Day16, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day16.parse(input);
+    for part in 1..=Day16.parts() {
+        let _ = Day16.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day17::Day17, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day17.parse(input);
+    for part in 1..=Day17.parts() {
+        let _ = Day17.solve_part(part, input, &params);
+    }
+});
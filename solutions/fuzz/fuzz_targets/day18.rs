@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day18::Day18, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day18.parse(input);
+    for part in 1..=Day18.parts() {
+        let _ = Day18.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day19::Day19, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day19.parse(input);
+    for part in 1..=Day19.parts() {
+        let _ = Day19.solve_part(part, input, &params);
+    }
+});
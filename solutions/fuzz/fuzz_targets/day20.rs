@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day20::Day20, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day20.parse(input);
+    for part in 1..=Day20.parts() {
+        let _ = Day20.solve_part(part, input, &params);
+    }
+});
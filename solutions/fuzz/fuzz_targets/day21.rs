@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day21::Day21, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day21.parse(input);
+    for part in 1..=Day21.parts() {
+        let _ = Day21.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day22::Day22, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day22.parse(input);
+    for part in 1..=Day22.parts() {
+        let _ = Day22.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day23::Day23, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day23.parse(input);
+    for part in 1..=Day23.parts() {
+        let _ = Day23.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day24::Day24, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day24.parse(input);
+    for part in 1..=Day24.parts() {
+        let _ = Day24.solve_part(part, input, &params);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2022_solutions::{day25::Day25, Parameters, Solver};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let params = Parameters::default();
+    let _ = Day25.parse(input);
+    for part in 1..=Day25.parts() {
+        let _ = Day25.solve_part(part, input, &params);
+    }
+});
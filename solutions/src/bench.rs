@@ -0,0 +1,139 @@
+use std::{fmt::Write, time::Duration};
+
+use crate::{
+    error::SolveError,
+    solver::{Parameters, Solver},
+    timing::format_duration,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Stats {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "min_ns", serialize_with = "crate::timing::serialize_nanos")
+    )]
+    pub min: Duration,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "median_ns",
+            serialize_with = "crate::timing::serialize_nanos"
+        )
+    )]
+    pub median: Duration,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "max_ns", serialize_with = "crate::timing::serialize_nanos")
+    )]
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &mut [Duration]) -> Option<Self> {
+        samples.sort();
+        let len = samples.len();
+        let median = if len % 2 == 1 {
+            samples[len / 2]
+        } else {
+            (*samples.get(len / 2)? + samples[len / 2 - 1]) / 2
+        };
+        Some(Self {
+            min: *samples.first()?,
+            median,
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Benchmark {
+    pub day: usize,
+    pub parse: Stats,
+    pub parts: Vec<Stats>,
+}
+
+pub fn benchmark(
+    solver: &dyn Solver,
+    input: &str,
+    params: &Parameters,
+    runs: usize,
+) -> Result<Benchmark, SolveError> {
+    let mut parse = vec![];
+    let mut parts = vec![vec![]; solver.parts()];
+    for _ in 0..runs {
+        let timed = solver.solve_timed(input, params)?;
+        parse.push(timed.parse);
+        for (samples, (result, elapsed)) in parts.iter_mut().zip(timed.parts) {
+            result?;
+            samples.push(elapsed);
+        }
+    }
+    let no_runs = || SolveError::invalid("benchmark needs at least one run");
+    Ok(Benchmark {
+        day: solver.day(),
+        parse: Stats::new(&mut parse).ok_or_else(no_runs)?,
+        parts: parts
+            .iter_mut()
+            .map(|samples| Stats::new(samples).ok_or_else(no_runs))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn steps(benchmark: &Benchmark) -> impl Iterator<Item = (String, &Stats)> {
+    std::iter::once(("parse".to_owned(), &benchmark.parse)).chain(
+        benchmark
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(idx, stats)| (format!("part{}", idx + 1), stats)),
+    )
+}
+
+pub fn to_table(benchmarks: &[Benchmark]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}\n",
+        "day", "step", "min", "median", "max"
+    );
+    for benchmark in benchmarks {
+        for (step, stats) in steps(benchmark) {
+            writeln!(
+                table,
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+                benchmark.day,
+                step,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+            )
+            .unwrap();
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples = [3, 1, 4, 2].map(Duration::from_millis);
+        let stats = Stats::new(&mut samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        assert_eq!(Stats::new(&mut []), None);
+
+        let benchmarks = [Benchmark {
+            day: 25,
+            parse: stats,
+            parts: vec![stats],
+        }];
+        assert_eq!(
+            to_table(&benchmarks).lines().nth(2).unwrap(),
+            " 25  part1      1.00ms      2.50ms      4.00ms"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{Parameters, Solver},
+};
+
+// The answers and parameters in a `*.expected` file, one `name: value` per
+// line. `part1` and `part2` are answers, any other name is a parameter, `#`
+// starts a comment and a `|` value takes the following lines indented by two
+// spaces, for answers spanning several lines:
+//
+//     part1: 13140
+//     part2: |
+//       ##..##..
+//       ###...##
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub answers: BTreeMap<usize, String>,
+    pub params: Parameters,
+}
+
+impl Expected {
+    pub fn parse(text: &str) -> Result<Self, SolveError> {
+        let mut expected = Self::default();
+        let mut lines = lines(text).peekable();
+        while let Some(line) = lines.next() {
+            if line.text.trim().is_empty() || line.text.starts_with('#') {
+                continue;
+            }
+            let (name, value) = line
+                .text
+                .split_once(':')
+                .ok_or_else(|| line.expected(Some(line.text), "\"name: value\""))?;
+            let name = name.trim();
+            let mut value = value.trim().to_owned();
+            if value == "|" {
+                let mut block = vec![];
+                while let Some(next) = lines.next_if(|next| next.text.starts_with("  ")) {
+                    block.push(&next.text[2..]);
+                }
+                value = block.join("\n");
+            }
+            match name.strip_prefix("part") {
+                Some(part) => {
+                    let part = line.parse(part)?;
+                    expected.answers.insert(part, value);
+                }
+                None => expected.params.set(name, line.parse(&value)?),
+            }
+        }
+        if expected.answers.is_empty() {
+            return Err(SolveError::invalid(
+                "expected at least one of part1 or part2",
+            ));
+        }
+        Ok(expected)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub day: usize,
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: Option<PathBuf>,
+}
+
+// Finds `<dir>/dayNN/*.txt`, each with its `*.expected` file when there is one.
+pub fn discover(dir: &Path) -> io::Result<Vec<Case>> {
+    let mut cases = vec![];
+    for entry in fs::read_dir(dir)? {
+        let day_dir = entry?.path();
+        let day = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok());
+        let day = match day {
+            Some(day) if day_dir.is_dir() => day,
+            _ => continue,
+        };
+        for entry in fs::read_dir(&day_dir)? {
+            let input = entry?.path();
+            if input.extension().and_then(|ext| ext.to_str()) != Some("txt") {
+                continue;
+            }
+            let expected = input.with_extension("expected");
+            let stem = input.file_stem().unwrap().to_string_lossy();
+            cases.push(Case {
+                day,
+                name: format!("day{:02}/{}", day, stem),
+                expected: expected.is_file().then_some(expected),
+                input,
+            });
+        }
+    }
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(cases)
+}
+
+// Solves the input with the expected parameters and lists every mismatch.
+// Trailing newlines of the answers are ignored.
+pub fn check(solver: &dyn Solver, input: &str, expected: &Expected) -> Result<(), String> {
+    for (name, _) in expected.params.iter() {
+        if !solver.params().iter().any(|param| param.name == name) {
+            return Err(format!("day {} has no parameter {}", solver.day(), name));
+        }
+    }
+    let mut mismatches = vec![];
+    let parsed = solver.parse(input);
+    for (part, answer) in &expected.answers {
+        let found = match &parsed {
+            Ok(parsed) => solver.solve_parsed(*part, parsed, &expected.params),
+            Err(err) => Err(err.clone()),
+        };
+        match found {
+            Ok(found) if found.trim_end_matches('\n') == answer => {}
+            Ok(found) => mismatches.push(format!(
+                "part {}: expected {:?}, found {:?}",
+                part,
+                answer,
+                found.trim_end_matches('\n')
+            )),
+            Err(err) => mismatches.push(format!("part {}: {}", part, err)),
+        }
+    }
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day01::Day01, day15::Day15};
+    use indoc::indoc;
+
+    #[test]
+    fn expected() {
+        let expected = Expected::parse(indoc! {"
+            # CRT output
+            part1: 13140
+            part2: |
+              ##..
+              ###.
+            half_grid: 10
+        "})
+        .unwrap();
+        assert_eq!(expected.answers[&1], "13140");
+        assert_eq!(expected.answers[&2], "##..\n###.");
+        assert_eq!(
+            expected.params.iter().collect::<Vec<_>>(),
+            [("half_grid", 10)]
+        );
+
+        assert_eq!(
+            Expected::parse("part1 24000").unwrap_err().to_string(),
+            "line 1, column 1: expected \"name: value\", found \"part1 24000\""
+        );
+        assert!(Expected::parse("half_grid: x").is_err());
+        assert!(Expected::parse("# nothing").is_err());
+
+        let input = "1000\n\n2000\n3000\n\n4000\n";
+        let expected = Expected::parse("part1: 5000\npart2: 10000").unwrap();
+        assert_eq!(check(&Day01, input, &expected), Ok(()));
+        let expected = Expected::parse("part1: 1000").unwrap();
+        assert_eq!(
+            check(&Day01, input, &expected),
+            Err("part 1: expected \"1000\", found \"5000\"".to_owned())
+        );
+        let expected = Expected::parse("part1: 1\nsize: 4").unwrap();
+        assert_eq!(
+            check(&Day15, "", &expected),
+            Err("day 15 has no parameter size".to_owned())
+        );
+    }
+}
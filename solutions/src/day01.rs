@@ -0,0 +1,82 @@
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    fn day(&self) -> usize {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<usize>, SolveError> {
+    let mut calories: Vec<usize> = vec![0];
+    for line in lines(input) {
+        if line.text.is_empty() {
+            calories.push(0);
+            continue;
+        }
+        *calories.last_mut().unwrap() += line.parse::<usize>(line.text)?;
+    }
+    Ok(calories)
+}
+
+fn part1(calories: Vec<usize>) -> usize {
+    *calories.iter().max().unwrap()
+}
+
+fn part2(mut calories: Vec<usize>) -> Result<usize, SolveError> {
+    if calories.len() < 3 {
+        return Err(SolveError::invalid("expected at least 3 elves"));
+    }
+    calories.sort_by(|a, b| b.cmp(a));
+    Ok(calories[0..=2].iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            1000
+            2000
+            3000
+
+            4000
+
+            5000
+            6000
+
+            7000
+            8000
+            9000
+
+            10000
+        "};
+        let (part1, part2) = Day01.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "24000");
+        assert_eq!(&part2, "45000");
+    }
+}
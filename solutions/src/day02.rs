@@ -0,0 +1,108 @@
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const POINTS_WON: usize = 6;
+const POINTS_DRAW: usize = 3;
+const POINTS_LOST: usize = 0;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    fn day(&self) -> usize {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, SolveError> {
+    let mut strategies: Vec<(usize, usize)> = Vec::new();
+
+    for round in lines(input) {
+        let mut signs = round.text.split(' ');
+        let opponent = round.next(&mut signs, "A, B or C")?;
+        let response = round.next(&mut signs, "X, Y or Z")?;
+        let opponent = match opponent {
+            "A" | "B" | "C" => opponent.as_bytes()[0] - b'A',
+            _ => return Err(round.expected(Some(opponent), "A, B or C")),
+        };
+        let response = match response {
+            "X" | "Y" | "Z" => response.as_bytes()[0] - b'X',
+            _ => return Err(round.expected(Some(response), "X, Y or Z")),
+        };
+        strategies.push((opponent as usize, response as usize));
+    }
+    Ok(strategies)
+}
+
+fn part1(strategies: Vec<(usize, usize)>) -> usize {
+    let mut sum = 0;
+    for (opponent, player) in strategies {
+        sum += get_points(player, opponent);
+    }
+    sum
+}
+
+fn part2(strategies: Vec<(usize, usize)>) -> usize {
+    let mut sum = 0;
+    for (opponent, outcome) in strategies {
+        let player = get_player_sign(opponent, outcome);
+        sum += get_points(player, opponent);
+    }
+    sum
+}
+
+fn get_points(player: usize, opponent: usize) -> usize {
+    let mut sum = get_outcome_points(player, opponent);
+    sum += player + 1;
+    sum
+}
+
+fn get_player_sign(opponent: usize, outcome: usize) -> usize {
+    (opponent + outcome + 2) % 3
+}
+
+fn get_outcome_points(player: usize, opponent: usize) -> usize {
+    if player == opponent {
+        return POINTS_DRAW;
+    }
+    if player == (opponent + 1) % 3 {
+        return POINTS_WON;
+    }
+    POINTS_LOST
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            A Y
+            B X
+            C Z
+        "};
+        let (part1, part2) = Day02.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "15");
+        assert_eq!(&part2, "12");
+    }
+}
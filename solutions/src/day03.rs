@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    fn day(&self) -> usize {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>, SolveError> {
+    let mut rucksacks = vec![];
+    for line in lines(input) {
+        if let Some(item) = line.text.matches(|c: char| !c.is_ascii_alphabetic()).next() {
+            return Err(line.error(item, format!("invalid item {:?}", item)));
+        }
+        if line.text.len() % 2 != 0 {
+            return Err(line.error(line.text, "rucksack has an odd number of items"));
+        }
+        rucksacks.push(line.text.to_owned());
+    }
+    Ok(rucksacks)
+}
+
+fn part1(rucksacks: Vec<String>) -> Result<usize, SolveError> {
+    let mut sum: usize = 0;
+    for (idx, rucksack) in rucksacks.iter().enumerate() {
+        let (first_compartment, second_compartment) = rucksack.split_at(rucksack.len() >> 1);
+        let first_compartment: HashSet<char> = first_compartment.chars().collect();
+        let second_compartment: HashSet<char> = second_compartment.chars().collect();
+        let intersection: Vec<&char> = first_compartment
+            .intersection(&second_compartment)
+            .collect();
+        sum += get_priority(intersection).ok_or_else(|| {
+            SolveError::at(idx + 1, 1, "expected exactly one item in both compartments")
+        })?;
+    }
+    Ok(sum)
+}
+
+fn part2(rucksacks: Vec<String>) -> Result<usize, SolveError> {
+    let mut sum: usize = 0;
+    for i in 0..rucksacks.len() / 3 {
+        let groups: Vec<HashSet<char>> = rucksacks[i * 3..=i * 3 + 2]
+            .iter()
+            .map(|group| group.chars().collect())
+            .collect();
+        let intersection: HashSet<char> =
+            groups.iter().skip(1).fold(groups[0].clone(), |acc, hs| {
+                acc.intersection(hs).cloned().collect()
+            });
+        let intersection = intersection.iter().collect();
+        sum += get_priority(intersection).ok_or_else(|| {
+            SolveError::at(i * 3 + 1, 1, "expected exactly one badge in the group")
+        })?;
+    }
+    Ok(sum)
+}
+
+fn get_priority(intersection: Vec<&char>) -> Option<usize> {
+    if intersection.len() != 1 {
+        return None;
+    }
+    let item = *intersection.first().unwrap();
+    if item.is_lowercase() {
+        return Some(*item as usize - 96);
+    }
+    Some(*item as usize - 38)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            vJrwpWtwJgWrhcsFMMfFFhFp
+            jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+            PmmdzqPrVvPwwTWBwg
+            wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+            ttgJtRGJQctTZtZT
+            CrZsJsPPZsGzwwsLwLmpwMDw
+        "};
+        let (part1, part2) = Day03.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "157");
+        assert_eq!(&part2, "70");
+    }
+}
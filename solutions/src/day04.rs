@@ -0,0 +1,114 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+type Pair = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    fn day(&self) -> usize {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pair>, SolveError> {
+    let mut pairs: Vec<Pair> = vec![];
+
+    for pair in lines(input) {
+        let mut ranges = pair.text.split(',');
+        let lhs = parse_range(&pair, pair.next(&mut ranges, "a section range")?)?;
+        let rhs = parse_range(&pair, pair.next(&mut ranges, "a section range")?)?;
+
+        pairs.push((lhs, rhs));
+    }
+    Ok(pairs)
+}
+
+fn parse_range(line: &Line, range: &str) -> Result<RangeInclusive<usize>, SolveError> {
+    let mut sections = range.split('-');
+    let start = line.parse_next(&mut sections, "a section")?;
+    let end = line.parse_next(&mut sections, "a section")?;
+    Ok(start..=end)
+}
+
+// Compares the bounds instead of collecting the sections, ranges can span the
+// whole of usize. An empty range is contained in any other and overlaps none.
+fn contains(outer: &RangeInclusive<usize>, inner: &RangeInclusive<usize>) -> bool {
+    inner.is_empty() || (outer.start() <= inner.start() && inner.end() <= outer.end())
+}
+
+fn overlaps(lhs: &RangeInclusive<usize>, rhs: &RangeInclusive<usize>) -> bool {
+    !lhs.is_empty() && !rhs.is_empty() && lhs.start() <= rhs.end() && rhs.start() <= lhs.end()
+}
+
+fn part1(pairs: Vec<Pair>) -> usize {
+    let mut sum: usize = 0;
+    for (lhs, rhs) in pairs {
+        if contains(&lhs, &rhs) || contains(&rhs, &lhs) {
+            sum += 1;
+        }
+    }
+    sum
+}
+
+fn part2(pairs: Vec<Pair>) -> usize {
+    let mut sum: usize = 0;
+    for (lhs, rhs) in pairs {
+        if overlaps(&lhs, &rhs) {
+            sum += 1;
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            2-4,6-8
+            2-3,4-5
+            5-7,7-9
+            2-8,3-7
+            6-6,4-6
+            2-6,4-8
+        "};
+        let (part1, part2) = Day04.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "2");
+        assert_eq!(&part2, "4");
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // Timed out collecting billions of sections into sets.
+        let input = "2-18446744073709551615,6-6\n7-8,9-18446744073709551615\n";
+        assert_eq!(
+            Day04.solve(input, &Parameters::default()).unwrap(),
+            ("1".to_owned(), "1".to_owned())
+        );
+    }
+}
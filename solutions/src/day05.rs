@@ -0,0 +1,201 @@
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+#[derive(Debug, Clone)]
+struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+    line: usize,
+}
+
+impl Instruction {
+    fn new(count: usize, from: usize, to: usize, line: usize) -> Self {
+        Self {
+            count,
+            from,
+            to,
+            line,
+        }
+    }
+
+    fn execute(&self, stacks: &mut [Vec<char>]) -> Result<(), SolveError> {
+        for _ in 0..self.count {
+            let el = stacks[self.from].pop().ok_or_else(|| self.empty_stack())?;
+            stacks[self.to].push(el);
+        }
+        Ok(())
+    }
+
+    fn execute_reverse(&self, stacks: &mut [Vec<char>]) -> Result<(), SolveError> {
+        let mut group: Vec<char> = vec![];
+        for _ in 0..self.count {
+            let el = stacks[self.from].pop().ok_or_else(|| self.empty_stack())?;
+            group.push(el);
+        }
+        group.reverse();
+        stacks[self.to].append(&mut group);
+        Ok(())
+    }
+
+    fn empty_stack(&self) -> SolveError {
+        SolveError::at(
+            self.line,
+            1,
+            format!("stack {} runs out of crates", self.from + 1),
+        )
+    }
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    fn day(&self) -> usize {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        part1(unpack(parsed)?)
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        part2(unpack(parsed)?)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Vec<char>>, Vec<Instruction>), SolveError> {
+    let input: Vec<Line> = lines(input).collect();
+    let mut input = input.split(|line| line.text.is_empty());
+    let mut arrangement: Vec<&Line> = input.next().unwrap().iter().collect();
+    let instruction_texts = input.next().ok_or_else(|| {
+        SolveError::invalid("expected a blank line between the stacks and the instructions")
+    })?;
+
+    let numbers = arrangement
+        .pop()
+        .ok_or_else(|| SolveError::invalid("expected a starting stack arrangement"))?;
+    let mut cols = Vec::<usize>::new();
+    for col in numbers.text.split_whitespace() {
+        match numbers.parse::<usize>(col)? {
+            0 => return Err(numbers.error(col, "stacks are numbered from 1")),
+            col => cols.push(col - 1),
+        }
+    }
+    arrangement.reverse();
+
+    let mut stacks = Vec::<Vec<char>>::new();
+
+    for col in cols {
+        let mut stack = Vec::<char>::new();
+        for row in arrangement.iter() {
+            let el = col
+                .checked_mul(4)
+                .and_then(|offset| row.text.chars().nth(offset + 1))
+                .unwrap_or(' ');
+            if el == ' ' {
+                break;
+            }
+            stack.push(el);
+        }
+        stacks.push(stack);
+    }
+
+    let mut instructions = Vec::<Instruction>::new();
+    for instruction in instruction_texts {
+        let mut instruction_parts = instruction.text.split(' ');
+        instruction.keyword(&mut instruction_parts, "move")?;
+        let count = instruction.parse_next(&mut instruction_parts, "a crate count")?;
+        instruction.keyword(&mut instruction_parts, "from")?;
+        let from = parse_stack(instruction, &mut instruction_parts, stacks.len())?;
+        instruction.keyword(&mut instruction_parts, "to")?;
+        let to = parse_stack(instruction, &mut instruction_parts, stacks.len())?;
+        instructions.push(Instruction::new(count, from, to, instruction.number));
+    }
+    Ok((stacks, instructions))
+}
+
+fn parse_stack<'a>(
+    line: &Line<'a>,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    stacks: usize,
+) -> Result<usize, SolveError> {
+    let token = line.next(tokens, "a stack number")?;
+    match line.parse::<usize>(token)? {
+        stack if (1..=stacks).contains(&stack) => Ok(stack - 1),
+        _ => Err(line.error(token, format!("there are only {} stacks", stacks))),
+    }
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> Result<String, SolveError> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(idx, x)| {
+            x.last()
+                .ok_or_else(|| SolveError::invalid(format!("stack {} ends up empty", idx + 1)))
+        })
+        .collect()
+}
+
+fn part1(
+    (mut stacks, instructions): (Vec<Vec<char>>, Vec<Instruction>),
+) -> Result<String, SolveError> {
+    for instruction in instructions {
+        instruction.execute(&mut stacks)?;
+    }
+    top_crates(&stacks)
+}
+
+fn part2(
+    (mut stacks, instructions): (Vec<Vec<char>>, Vec<Instruction>),
+) -> Result<String, SolveError> {
+    for instruction in instructions {
+        instruction.execute_reverse(&mut stacks)?;
+    }
+    top_crates(&stacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+                [D]    
+            [N] [C]    
+            [Z] [M] [P]
+             1   2   3 
+            
+            move 1 from 2 to 1
+            move 3 from 1 to 3
+            move 2 from 2 to 1
+            move 1 from 1 to 2
+        "};
+        let (part1, part2) = Day05.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "CMZ");
+        assert_eq!(&part2, "MCD");
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // The stack number overflowed while looking up its column.
+        let input = "[A]\n 1 4611686018427387905\n\nmove 1 from 1 to 2\n";
+        assert_eq!(
+            Day05.solve(input, &Parameters::default()).unwrap_err(),
+            SolveError::invalid("stack 1 ends up empty")
+        );
+    }
+}
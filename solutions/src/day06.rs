@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::SolveError,
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    fn day(&self) -> usize {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        part1(unpack(parsed)?)
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        part2(unpack(parsed)?)
+    }
+}
+
+fn parse_input(input: &str) -> Vec<char> {
+    input.chars().collect()
+}
+
+fn is_different_window(window: &[char]) -> bool {
+    let set: HashSet<char> = HashSet::from_iter(window.iter().cloned());
+    set.len() == window.len()
+}
+
+fn get_len_to_start_marker(stream: &[char], w_size: usize) -> Option<usize> {
+    let mut window_end = w_size;
+    while window_end <= stream.len() {
+        if is_different_window(&stream[window_end - w_size..window_end]) {
+            return Some(window_end);
+        }
+        window_end += 1;
+    }
+    None
+}
+
+fn part1(stream: Vec<char>) -> Result<String, SolveError> {
+    if let Some(res) = get_len_to_start_marker(&stream, 4) {
+        return Ok(res.to_string());
+    }
+    Err(SolveError::invalid("no start-of-packet marker found"))
+}
+
+fn part2(stream: Vec<char>) -> Result<String, SolveError> {
+    if let Some(res) = get_len_to_start_marker(&stream, 14) {
+        return Ok(res.to_string());
+    }
+    Err(SolveError::invalid("no start-of-message marker found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            mjqjpqmgbljsphdztnvjfqwrcgsmlb
+        "};
+        let (part1, part2) = Day06.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "7");
+        assert_eq!(&part2, "19");
+    }
+}
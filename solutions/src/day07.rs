@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const MAX_CAPACITY: usize = 70000000;
+const SPACE_REQUIRED: usize = 30000000;
+const MAX_DIR_SIZE: usize = 100000;
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    fn day(&self) -> usize {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn leave_directory(
+    dirs: &mut Vec<String>,
+    dir_sums: &mut HashMap<String, usize>,
+) -> Result<(), SolveError> {
+    let amount = *dir_sums.get(dirs.join(";").as_str()).unwrap();
+    dirs.pop();
+    add_size(dir_sums.get_mut(dirs.join(";").as_str()).unwrap(), amount)
+}
+
+fn add_size(sum: &mut usize, size: usize) -> Result<(), SolveError> {
+    *sum = sum
+        .checked_add(size)
+        .ok_or_else(|| SolveError::invalid("directory sizes do not fit in 64 bits"))?;
+    Ok(())
+}
+
+fn enter_directory(dirs: &mut Vec<String>, dir_sums: &mut HashMap<String, usize>, dir: String) {
+    dirs.push(dir);
+    if !dir_sums.contains_key(dirs.join(";").as_str()) {
+        dir_sums.insert(dirs.join(";"), 0);
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, usize>, SolveError> {
+    let mut dirs = Vec::<String>::new();
+    let mut dir_sums = HashMap::<String, usize>::new();
+    for line in lines(input) {
+        let mut parts = line.text.split(' ');
+        match line.next(&mut parts, "a command or a directory listing")? {
+            "$" => match line.next(&mut parts, "a command")? {
+                "cd" => match line.next(&mut parts, "a directory")? {
+                    ".." if dirs.len() > 1 => leave_directory(&mut dirs, &mut dir_sums)?,
+                    token @ ".." => {
+                        return Err(line.error(token, "cannot leave the outermost directory"))
+                    }
+                    dir => enter_directory(&mut dirs, &mut dir_sums, dir.to_string()),
+                },
+                "ls" => {}
+                command => return Err(line.expected(Some(command), "\"cd\" or \"ls\"")),
+            },
+            "dir" => {}
+            file_size => {
+                let file_size = line.parse::<usize>(file_size)?;
+                let sum = dir_sums
+                    .get_mut(dirs.join(";").as_str())
+                    .ok_or_else(|| line.error(line.text, "listing outside of a directory"))?;
+                add_size(sum, file_size)?;
+            }
+        }
+    }
+    while dirs.len() > 1 {
+        leave_directory(&mut dirs, &mut dir_sums)?;
+    }
+    Ok(dir_sums)
+}
+
+fn part1(dir_sums: HashMap<String, usize>) -> usize {
+    let sum = dir_sums
+        .values()
+        .filter(|x| **x <= MAX_DIR_SIZE)
+        .fold(0, |acc, x| acc + *x);
+    sum
+}
+
+fn part2(dir_sums: HashMap<String, usize>) -> Result<usize, SolveError> {
+    let total: usize = *dir_sums
+        .get("/")
+        .ok_or_else(|| SolveError::invalid("expected the root directory \"/\""))?;
+    if total > MAX_CAPACITY {
+        return Err(SolveError::invalid("files exceed the disk capacity"));
+    }
+    let search_size = SPACE_REQUIRED.saturating_sub(MAX_CAPACITY - total);
+    let mut dirs: Vec<usize> = dir_sums
+        .values()
+        .cloned()
+        .filter(|x| *x >= search_size)
+        .collect();
+    dirs.sort();
+    Ok(*dirs.first().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            $ cd /
+            $ ls
+            dir a
+            14848514 b.txt
+            8504156 c.dat
+            dir d
+            $ cd a
+            $ ls
+            dir e
+            29116 f
+            2557 g
+            62596 h.lst
+            $ cd e
+            $ ls
+            584 i
+            $ cd ..
+            $ cd ..
+            $ cd d
+            $ ls
+            4060174 j
+            8033020 d.log
+            5626152 d.ext
+            7214296 k
+        "};
+        let (part1, part2) = Day07.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "95437");
+        assert_eq!(&part2, "24933642");
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // Adding up the sizes overflowed, within a directory and into its parent.
+        for input in [
+            "$ cd /\n18446744073709551615 a\n1 b\n",
+            "$ cd /\n1 a\n$ cd b\n18446744073709551615 c\n",
+        ] {
+            assert_eq!(
+                Day07.solve(input, &Parameters::default()).unwrap_err(),
+                SolveError::invalid("directory sizes do not fit in 64 bits")
+            );
+        }
+    }
+}
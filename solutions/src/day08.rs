@@ -1,25 +1,61 @@
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
 const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
 
-pub fn solve(input: &str) -> (String, String) {
-    let part1 = part1(parse_input(input));
-    let part2 = part2(parse_input(input));
+pub struct Day08;
+
+impl Solver for Day08 {
+    fn day(&self) -> usize {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<usize>> {
-    input
-        .lines()
-        .map(|x| {
-            x.chars()
-                .map(|tree| tree.to_digit(10).unwrap() as usize)
-                .collect()
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<Vec<usize>>, SolveError> {
+    let mut forest: Vec<Vec<usize>> = vec![];
+    for line in lines(input) {
+        let row = line
+            .text
+            .char_indices()
+            .map(|(idx, tree)| {
+                tree.to_digit(10)
+                    .map(|height| height as usize)
+                    .ok_or_else(|| {
+                        SolveError::at(line.number, idx + 1, format!("invalid tree {:?}", tree))
+                    })
+            })
+            .collect::<Result<Vec<usize>, SolveError>>()?;
+        if row.is_empty() || matches!(forest.first(), Some(first) if first.len() != row.len()) {
+            return Err(line.error(line.text, "rows must be non-empty and of equal length"));
+        }
+        forest.push(row);
+    }
+    if forest.is_empty() {
+        return Err(SolveError::invalid("expected a grid of trees"));
+    }
+    Ok(forest)
 }
 
 fn visibility_and_view_distance(
-    forest: &Vec<Vec<usize>>,
+    forest: &[Vec<usize>],
     mut row: usize,
     mut col: usize,
     direction: (isize, isize),
@@ -92,7 +128,7 @@ mod tests {
             33549
             35390
         "};
-        let (part1, part2) = solve(input);
+        let (part1, part2) = Day08.solve(input, &Parameters::default()).unwrap();
 
         assert_eq!(&part1, "21");
         assert_eq!(&part2, "8");
@@ -0,0 +1,253 @@
+use std::{cmp, collections::HashSet};
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+// Real inputs move the head about ten thousand steps, every step is simulated.
+pub(crate) const MAX_STEPS: usize = 1_000_000;
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+struct Position {
+    x: isize,
+    y: isize,
+}
+
+impl Position {
+    fn new() -> Self {
+        Self { x: 0, y: 0 }
+    }
+
+    fn move_along(&mut self, direction: char) {
+        match direction {
+            'U' => self.y += 1,
+            'D' => self.y -= 1,
+            'R' => self.x += 1,
+            'L' => self.x -= 1,
+            _ => unreachable!(),
+        }
+    }
+
+    fn follow(&mut self, head: &Self) {
+        if self.is_in_range(head) {
+            return;
+        }
+        self.x -= self.x.cmp(&head.x) as isize;
+        self.y -= self.y.cmp(&head.y) as isize;
+    }
+
+    fn is_in_range(&self, rhs: &Self) -> bool {
+        cmp::max((self.x - rhs.x).abs(), (self.y - rhs.y).abs()) <= 1
+    }
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    fn day(&self) -> usize {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(char, usize)>, SolveError> {
+    let mut instructions = vec![];
+    let mut total = 0usize;
+    for line in lines(input) {
+        let mut parts = line.text.split(' ');
+        let direction = match line.next(&mut parts, "a direction")? {
+            "U" => 'U',
+            "D" => 'D',
+            "R" => 'R',
+            "L" => 'L',
+            token => return Err(line.expected(Some(token), "U, D, R or L")),
+        };
+        let token = line.next(&mut parts, "a step count")?;
+        let steps = line.parse(token)?;
+        total = total.saturating_add(steps);
+        if total > MAX_STEPS {
+            return Err(line.error(
+                token,
+                format!("the head moves more than {} steps in total", MAX_STEPS),
+            ));
+        }
+        instructions.push((direction, steps));
+    }
+    Ok(instructions)
+}
+
+// The rope moved one step of the head at a time, for the solver and the
+// visualization, with any number of knots. The head is the first knot and the
+// tail the last, which is the head itself for a single knot.
+#[derive(Clone)]
+pub struct Rope {
+    instructions: Vec<(char, usize)>,
+    // The instruction being carried out and how many of its steps are done.
+    current: usize,
+    taken: usize,
+    knots: Vec<Position>,
+    visited: HashSet<Position>,
+}
+
+impl Rope {
+    pub fn new(input: &str, knots: usize) -> Result<Self, SolveError> {
+        if knots == 0 {
+            return Err(SolveError::invalid("a rope needs at least one knot"));
+        }
+        Ok(Self::from_instructions(parse_input(input)?, knots))
+    }
+
+    fn from_instructions(instructions: Vec<(char, usize)>, knots: usize) -> Self {
+        Self {
+            instructions,
+            current: 0,
+            taken: 0,
+            knots: vec![Position::new(); knots],
+            visited: HashSet::new(),
+        }
+    }
+
+    // Moves the head one step, returning false once every instruction is done.
+    pub fn step(&mut self) -> bool {
+        let direction = loop {
+            match self.instructions.get(self.current) {
+                Some(&(direction, amount)) if self.taken < amount => break direction,
+                Some(_) => {
+                    self.current += 1;
+                    self.taken = 0;
+                }
+                None => return false,
+            }
+        };
+        self.taken += 1;
+        self.knots.first_mut().unwrap().move_along(direction);
+        let mut previous_knot = *self.knots.first().unwrap();
+        for knot in self.knots.iter_mut().skip(1) {
+            knot.follow(&previous_knot);
+            previous_knot = *knot;
+        }
+        self.visited.insert(*self.knots.last().unwrap());
+        true
+    }
+
+    pub fn knots(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.knots.iter().map(|knot| (knot.x, knot.y))
+    }
+
+    pub fn tail(&self) -> (isize, isize) {
+        let tail = self.knots.last().unwrap();
+        (tail.x, tail.y)
+    }
+
+    // The number of positions the tail has been on.
+    pub fn visited(&self) -> usize {
+        self.visited.len()
+    }
+
+    // The index of the instruction being carried out, along with it.
+    pub fn instruction(&self) -> Option<(usize, (char, usize))> {
+        let instruction = *self.instructions.get(self.current)?;
+        Some((self.current, instruction))
+    }
+
+    pub fn instructions(&self) -> usize {
+        self.instructions.len()
+    }
+}
+
+fn simulate_knots(instructions: Vec<(char, usize)>, amount: usize) -> usize {
+    let mut rope = Rope::from_instructions(instructions, amount);
+    while rope.step() {}
+    rope.visited()
+}
+
+fn part1(instructions: Vec<(char, usize)>) -> usize {
+    simulate_knots(instructions, 2)
+}
+
+fn part2(instructions: Vec<(char, usize)>) -> usize {
+    simulate_knots(instructions, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            R 4
+            U 4
+            L 3
+            D 1
+            R 4
+            D 1
+            L 5
+            R 2
+        "};
+        let (part1, part2) = Day09.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "13");
+        assert_eq!(&part2, "1");
+    }
+
+    #[test]
+    fn sample2() {
+        let input = indoc! {"
+            R 5
+            U 8
+            L 8
+            D 3
+            R 17
+            D 10
+            L 25
+            U 20        
+        "};
+        let (_, part2) = Day09.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part2, "36");
+    }
+
+    #[test]
+    fn rope() {
+        let input = indoc! {"
+            R 5
+            U 8
+            L 8
+        "};
+        let mut rope = Rope::new(input, 3).unwrap();
+        for _ in 0..7 {
+            rope.step();
+        }
+        assert_eq!(rope.knots().collect::<Vec<_>>(), [(5, 2), (5, 1), (4, 1)]);
+        assert_eq!(rope.instruction(), Some((1, ('U', 8))));
+        assert!(Rope::new(input, 0).is_err());
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // Ran for ages simulating every one of the steps.
+        let input = "R 9999999999999999999\n";
+        assert_eq!(
+            Day09.solve(input, &Parameters::default()).unwrap_err(),
+            SolveError::at(1, 3, "the head moves more than 1000000 steps in total")
+        );
+    }
+}
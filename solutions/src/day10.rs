@@ -0,0 +1,379 @@
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const CRT_WIDTH: isize = 40;
+
+#[derive(Clone)]
+enum Instruction {
+    Addx(isize),
+    Noop,
+}
+
+struct Cpu {
+    x: isize,
+    cycle: isize,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Self { x: 1, cycle: 1 }
+    }
+
+    fn execute_cycle(&mut self, instruction: &Instruction) -> Result<(), SolveError> {
+        match instruction {
+            Instruction::Noop => {}
+            Instruction::Addx(val) => self.x = self.x.checked_add(*val).ok_or_else(overflow)?,
+        }
+        self.cycle += 1;
+        Ok(())
+    }
+
+    fn render(&self) -> char {
+        let position = (self.cycle - 1) % CRT_WIDTH;
+        if position.abs_diff(self.x) <= 1 {
+            return '#';
+        }
+        '.'
+    }
+}
+
+// What the screen drew during one cycle: the pixel under the beam, counting
+// rows and columns from 0, and whether the three pixel wide sprite around the
+// X register covered it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pixel {
+    pub cycle: usize,
+    pub row: usize,
+    pub column: usize,
+    pub sprite: isize,
+    pub lit: bool,
+}
+
+// The program drawing the screen one cycle at a time, for the solver and the
+// screen on the day's result.
+pub struct Crt {
+    cpu: Cpu,
+    instructions: Vec<Instruction>,
+    next: usize,
+}
+
+impl Crt {
+    pub fn new(input: &str) -> Result<Self, SolveError> {
+        Ok(Self::from_instructions(parse_input(input)?))
+    }
+
+    fn from_instructions(instructions: Vec<Instruction>) -> Self {
+        Self {
+            cpu: Cpu::new(),
+            instructions,
+            next: 0,
+        }
+    }
+
+    // Draws the pixel under the beam and runs the cycle, or returns None once
+    // the program ended.
+    pub fn step(&mut self) -> Result<Option<Pixel>, SolveError> {
+        let instruction = match self.instructions.get(self.next) {
+            Some(instruction) => instruction,
+            None => return Ok(None),
+        };
+        let position = (self.cpu.cycle - 1) as usize;
+        let pixel = Pixel {
+            cycle: self.cpu.cycle as usize,
+            row: position / CRT_WIDTH as usize,
+            column: position % CRT_WIDTH as usize,
+            sprite: self.cpu.x,
+            lit: self.cpu.render() == '#',
+        };
+        self.cpu.execute_cycle(instruction)?;
+        self.next += 1;
+        Ok(Some(pixel))
+    }
+}
+
+fn overflow() -> SolveError {
+    SolveError::invalid("the X register overflows")
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    fn day(&self) -> usize {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        part2(unpack(parsed)?)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, SolveError> {
+    let mut instructions = vec![];
+    for line in lines(input) {
+        let mut parts = line.text.split(' ');
+        match line.next(&mut parts, "an instruction")? {
+            "addx" => {
+                instructions.push(Instruction::Noop);
+                instructions.push(Instruction::Addx(line.parse_next(&mut parts, "a value")?));
+            }
+            "noop" => instructions.push(Instruction::Noop),
+            token => return Err(line.expected(Some(token), "\"addx\" or \"noop\"")),
+        }
+    }
+    Ok(instructions)
+}
+
+fn part1(instructions: Vec<Instruction>) -> Result<isize, SolveError> {
+    let mut cpu = Cpu::new();
+    let mut sum: isize = 0;
+    for instruction in instructions {
+        cpu.execute_cycle(&instruction)?;
+        if (cpu.cycle + CRT_WIDTH / 2) % CRT_WIDTH == 0 {
+            let strength = cpu.cycle.checked_mul(cpu.x).ok_or_else(overflow)?;
+            sum = sum.checked_add(strength).ok_or_else(overflow)?;
+        }
+    }
+    Ok(sum)
+}
+
+fn part2(instructions: Vec<Instruction>) -> Result<String, SolveError> {
+    let mut crt = Crt::from_instructions(instructions);
+    let mut output: String = String::new();
+    while let Some(pixel) = crt.step()? {
+        output.push(if pixel.lit { '#' } else { '.' });
+        if pixel.column == CRT_WIDTH as usize - 1 {
+            output.push('\n');
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            addx 15
+            addx -11
+            addx 6
+            addx -3
+            addx 5
+            addx -1
+            addx -8
+            addx 13
+            addx 4
+            noop
+            addx -1
+            addx 5
+            addx -1
+            addx 5
+            addx -1
+            addx 5
+            addx -1
+            addx 5
+            addx -1
+            addx -35
+            addx 1
+            addx 24
+            addx -19
+            addx 1
+            addx 16
+            addx -11
+            noop
+            noop
+            addx 21
+            addx -15
+            noop
+            noop
+            addx -3
+            addx 9
+            addx 1
+            addx -3
+            addx 8
+            addx 1
+            addx 5
+            noop
+            noop
+            noop
+            noop
+            noop
+            addx -36
+            noop
+            addx 1
+            addx 7
+            noop
+            noop
+            noop
+            addx 2
+            addx 6
+            noop
+            noop
+            noop
+            noop
+            noop
+            addx 1
+            noop
+            noop
+            addx 7
+            addx 1
+            noop
+            addx -13
+            addx 13
+            addx 7
+            noop
+            addx 1
+            addx -33
+            noop
+            noop
+            noop
+            addx 2
+            noop
+            noop
+            noop
+            addx 8
+            noop
+            addx -1
+            addx 2
+            addx 1
+            noop
+            addx 17
+            addx -9
+            addx 1
+            addx 1
+            addx -3
+            addx 11
+            noop
+            noop
+            addx 1
+            noop
+            addx 1
+            noop
+            noop
+            addx -13
+            addx -19
+            addx 1
+            addx 3
+            addx 26
+            addx -30
+            addx 12
+            addx -1
+            addx 3
+            addx 1
+            noop
+            noop
+            noop
+            addx -9
+            addx 18
+            addx 1
+            addx 2
+            noop
+            noop
+            addx 9
+            noop
+            noop
+            noop
+            addx -1
+            addx 2
+            addx -37
+            addx 1
+            addx 3
+            noop
+            addx 15
+            addx -21
+            addx 22
+            addx -6
+            addx 1
+            noop
+            addx 2
+            addx 1
+            noop
+            addx -10
+            noop
+            noop
+            addx 20
+            addx 1
+            addx 2
+            addx 2
+            addx -6
+            addx -11
+            noop
+            noop
+            noop
+        "};
+        let (part1, part2) = Day10.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "13140");
+        assert_eq!(
+            &part2,
+            indoc! {"
+                ##..##..##..##..##..##..##..##..##..##..
+                ###...###...###...###...###...###...###.
+                ####....####....####....####....####....
+                #####.....#####.....#####.....#####.....
+                ######......######......######......####
+                #######.......#######.......#######.....
+            "}
+        );
+    }
+
+    #[test]
+    fn crt() {
+        let input = indoc! {"
+            addx 15
+            addx -11
+        "};
+        let mut crt = Crt::new(input).unwrap();
+        let pixels: Vec<Pixel> = (0..3).map(|_| crt.step().unwrap().unwrap()).collect();
+        assert_eq!(
+            pixels.last(),
+            Some(&Pixel {
+                cycle: 3,
+                row: 0,
+                column: 2,
+                sprite: 16,
+                lit: false,
+            })
+        );
+        assert!(crt.step().unwrap().is_some());
+        assert!(crt.step().unwrap().is_none());
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // The register and the signal strength overflowed.
+        for input in [
+            "addx 9223372036854775807\naddx 1\n",
+            "addx 9223372036854775806\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\n\
+             noop\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\nnoop\n",
+        ] {
+            assert_eq!(
+                Day10
+                    .solve_part(1, input, &Parameters::default())
+                    .unwrap_err(),
+                SolveError::invalid("the X register overflows")
+            );
+        }
+        let input = "addx 9223372036854775806\nnoop\n";
+        assert_eq!(
+            Day10.solve_part(2, input, &Parameters::default()).unwrap(),
+            "##."
+        );
+    }
+}
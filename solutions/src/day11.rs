@@ -0,0 +1,291 @@
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum Operator {
+    Add,
+    Mul,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    Number(u64),
+    ItemValue,
+}
+
+#[derive(Clone, Debug)]
+struct Monkey {
+    items: Vec<u64>,
+    operator: Operator,
+    operand: Operand,
+    test: u64,
+    result: (u64, u64),
+}
+
+impl Operator {
+    // Computed in u128 so the product of two worry levels always fits, part 2
+    // brings it back below the common multiple.
+    fn compute(&self, lhs: u64, rhs: u64) -> u128 {
+        match self {
+            Self::Add => lhs as u128 + rhs as u128,
+            Self::Mul => lhs as u128 * rhs as u128,
+        }
+    }
+}
+
+impl Monkey {
+    fn new(
+        items: Vec<u64>,
+        operator: Operator,
+        operand: Operand,
+        test: u64,
+        result: (u64, u64),
+    ) -> Self {
+        Self {
+            items,
+            operator,
+            operand,
+            test,
+            result,
+        }
+    }
+
+    fn throw_everything(
+        &mut self,
+        divide: bool,
+        common_multiple: u64,
+    ) -> Result<Vec<(u64, u64)>, SolveError> {
+        let mut throw_results = vec![];
+        for item in self.items.clone() {
+            throw_results.push(self.get_throw_result(item, divide, common_multiple)?);
+        }
+        self.items = vec![];
+        Ok(throw_results)
+    }
+
+    fn get_throw_result(
+        &self,
+        item: u64,
+        divide: bool,
+        common_multiple: u64,
+    ) -> Result<(u64, u64), SolveError> {
+        let operand = match self.operand {
+            Operand::Number(val) => val,
+            Operand::ItemValue => item,
+        };
+        let mut item = self.operator.compute(item, operand);
+        if divide {
+            item /= 3;
+        } else {
+            item %= common_multiple as u128;
+        }
+        let item = u64::try_from(item)
+            .map_err(|_| SolveError::invalid("worry levels do not fit in 64 bits"))?;
+        Ok((self.get_next_monkey(item), item))
+    }
+
+    fn get_next_monkey(&self, item: u64) -> u64 {
+        if item.is_multiple_of(self.test) {
+            return self.result.0;
+        }
+        self.result.1
+    }
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    fn day(&self) -> usize {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey in the Middle"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn parse_after<'a>(line: &Line<'a>, prefix: &str) -> Result<&'a str, SolveError> {
+    let text = line.text.trim();
+    text.strip_prefix(prefix)
+        .ok_or_else(|| line.expected(Some(text), &format!("{:?}", prefix.trim())))
+}
+
+fn next_line<'a, 'b>(
+    note: &mut impl Iterator<Item = &'b Line<'a>>,
+    monkey: usize,
+) -> Result<&'b Line<'a>, SolveError> {
+    note.next().ok_or_else(|| {
+        SolveError::invalid(format!("the notes on monkey {} are incomplete", monkey))
+    })
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Monkey>, u64), SolveError> {
+    let input: Vec<Line> = lines(input).collect();
+    let notes = input.split(|line| line.text.is_empty());
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut common_multiple: u64 = 1;
+    for (idx, note) in notes.enumerate() {
+        let mut note = note.iter().skip(1);
+        let line = next_line(&mut note, idx)?;
+        let items = parse_after(line, "Starting items:")?.trim_start();
+        let items: Vec<u64> = if items.is_empty() {
+            vec![]
+        } else {
+            items
+                .split(", ")
+                .map(|x| line.parse(x))
+                .collect::<Result<_, _>>()?
+        };
+        let line = next_line(&mut note, idx)?;
+        let mut operation = parse_after(line, "Operation: new = old ")?.split(' ');
+        let operator = match line.next(&mut operation, "an operator")? {
+            "*" => Operator::Mul,
+            "+" => Operator::Add,
+            token => return Err(line.expected(Some(token), "\"*\" or \"+\"")),
+        };
+        let operand = match line.next(&mut operation, "an operand")? {
+            "old" => Operand::ItemValue,
+            token => Operand::Number(line.parse(token)?),
+        };
+        let line = next_line(&mut note, idx)?;
+        let test_token = parse_after(line, "Test: divisible by ")?;
+        let test: u64 = line.parse(test_token)?;
+        if test == 0 {
+            return Err(line.error(test_token, "cannot test divisibility by 0"));
+        }
+        let line = next_line(&mut note, idx)?;
+        let positive: u64 = line.parse(parse_after(line, "If true: throw to monkey ")?)?;
+        let line = next_line(&mut note, idx)?;
+        let negative: u64 = line.parse(parse_after(line, "If false: throw to monkey ")?)?;
+        let monkey = Monkey::new(items, operator, operand, test, (positive, negative));
+        common_multiple = common_multiple
+            .checked_mul(test)
+            .ok_or_else(|| SolveError::invalid("the divisibility tests are too large"))?;
+        monkeys.push(monkey);
+    }
+    if monkeys.len() < 2 {
+        return Err(SolveError::invalid("expected at least 2 monkeys"));
+    }
+    for (idx, monkey) in monkeys.iter().enumerate() {
+        if monkey.result.0 as usize >= monkeys.len() || monkey.result.1 as usize >= monkeys.len() {
+            return Err(SolveError::invalid(format!(
+                "monkey {} throws to a monkey that does not exist",
+                idx
+            )));
+        }
+    }
+    Ok((monkeys, common_multiple))
+}
+
+fn simulate_monkeys(
+    (mut monkeys, common_multiple): (Vec<Monkey>, u64),
+    rounds: u64,
+    divide: bool,
+) -> Result<u64, SolveError> {
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let monkey = monkeys.get_mut(i).unwrap();
+            *inspections.get_mut(i).unwrap() += monkey.items.len();
+            for (next_monkey, item) in monkey.throw_everything(divide, common_multiple)? {
+                let next_monkey = monkeys.get_mut(next_monkey as usize).unwrap();
+                next_monkey.items.push(item);
+            }
+        }
+    }
+    inspections.sort();
+    Ok(inspections.pop().unwrap() as u64 * inspections.pop().unwrap() as u64)
+}
+
+fn part1(input: (Vec<Monkey>, u64)) -> Result<u64, SolveError> {
+    simulate_monkeys(input, 20, true)
+}
+
+fn part2(input: (Vec<Monkey>, u64)) -> Result<u64, SolveError> {
+    simulate_monkeys(input, 10_000, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            Monkey 0:
+              Starting items: 79, 98
+              Operation: new = old * 19
+              Test: divisible by 23
+                If true: throw to monkey 2
+                If false: throw to monkey 3
+            
+            Monkey 1:
+              Starting items: 54, 65, 75, 74
+              Operation: new = old + 6
+              Test: divisible by 19
+                If true: throw to monkey 2
+                If false: throw to monkey 0
+            
+            Monkey 2:
+              Starting items: 79, 60, 97
+              Operation: new = old * old
+              Test: divisible by 13
+                If true: throw to monkey 1
+                If false: throw to monkey 3
+            
+            Monkey 3:
+              Starting items: 74
+              Operation: new = old + 3
+              Test: divisible by 17
+                If true: throw to monkey 0
+                If false: throw to monkey 1
+        "};
+        let (part1, part2) = Day11.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "10605");
+        assert_eq!(&part2, "2713310158");
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // Squaring a worry level overflowed, part 2 keeps it below the common
+        // multiple of the tests so only part 1 runs out of bits.
+        let input = indoc! {"
+            Monkey 0:
+              Starting items: 8589934592
+              Operation: new = old * old
+              Test: divisible by 1
+                If true: throw to monkey 1
+                If false: throw to monkey 1
+
+            Monkey 1:
+              Starting items:
+              Operation: new = old + 0
+              Test: divisible by 18446744073709551557
+                If true: throw to monkey 0
+                If false: throw to monkey 0
+        "};
+        assert_eq!(
+            Day11
+                .solve_part(1, input, &Parameters::default())
+                .unwrap_err(),
+            SolveError::invalid("worry levels do not fit in 64 bits")
+        );
+        assert!(Day11.solve_part(2, input, &Parameters::default()).is_ok());
+    }
+}
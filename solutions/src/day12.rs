@@ -0,0 +1,325 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+type Position = (usize, usize);
+
+#[derive(Clone)]
+struct Heightmap {
+    nodes: HashMap<Position, Node>,
+    start: Position,
+    end: Position,
+    size: (usize, usize),
+}
+
+#[derive(Clone)]
+struct Node {
+    value: char,
+    edges: Vec<Position>,
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    fn day(&self) -> usize {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Hill Climbing Algorithm"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Heightmap, SolveError> {
+    let mut rows: Vec<Vec<char>> = vec![];
+    for line in lines(input) {
+        let invalid = |c: char| !c.is_ascii_lowercase() && c != 'S' && c != 'E';
+        if let Some(square) = line.text.matches(invalid).next() {
+            return Err(line.error(square, format!("invalid square {:?}", square)));
+        }
+        if line.text.is_empty()
+            || matches!(rows.first(), Some(first) if first.len() != line.text.len())
+        {
+            return Err(line.error(line.text, "rows must be non-empty and of equal length"));
+        }
+        rows.push(line.text.chars().collect());
+    }
+
+    let h = rows.len() as isize;
+    let w = rows.first().map_or(0, |row| row.len()) as isize;
+
+    let mut start = None;
+    let mut end = None;
+
+    let mut nodes: HashMap<Position, Node> = HashMap::new();
+
+    for y in 0..h {
+        for x in 0..w {
+            let current = *rows.get(y as usize).unwrap().get(x as usize).unwrap();
+            if current == 'S' {
+                start = Some((x as usize, y as usize));
+            }
+            if current == 'E' {
+                end = Some((x as usize, y as usize));
+            }
+            let mut edges = vec![];
+            for direction in DIRECTIONS {
+                if is_edge(direction, x, y, w, h) {
+                    continue;
+                }
+                let to: Position = ((direction.0 + x) as usize, (direction.1 + y) as usize);
+                let neighbor = *rows.get(to.1).unwrap().get(to.0).unwrap();
+                if no_edge(current, neighbor) {
+                    continue;
+                }
+                edges.push(to);
+            }
+            nodes.insert(
+                (x as usize, y as usize),
+                Node {
+                    value: current,
+                    edges,
+                },
+            );
+        }
+    }
+    let start = start.ok_or_else(|| SolveError::invalid("expected a start square \"S\""))?;
+    let end = end.ok_or_else(|| SolveError::invalid("expected a best signal square \"E\""))?;
+    Ok(Heightmap {
+        nodes,
+        start,
+        end,
+        size: (w as usize, h as usize),
+    })
+}
+
+fn is_edge(direction: (isize, isize), x: isize, y: isize, w: isize, h: isize) -> bool {
+    x + direction.0 < 0 || y + direction.1 < 0 || x + direction.0 >= w || y + direction.1 >= h
+}
+
+fn no_edge(current: char, neighbor: char) -> bool {
+    get_value(neighbor) as isize - get_value(current) as isize > 1
+}
+
+fn get_value(input: char) -> char {
+    if input == 'S' {
+        return 'a';
+    }
+    if input == 'E' {
+        return 'z';
+    }
+    input
+}
+
+fn part1(heightmap: Heightmap) -> Result<usize, SolveError> {
+    let start = heightmap.start;
+    search(heightmap, start, 'E')
+}
+
+// Part 2 walks down from the end, so every step goes the other way.
+fn part2(mut heightmap: Heightmap) -> Result<usize, SolveError> {
+    let mut reversed: HashMap<Position, Vec<Position>> = HashMap::new();
+    for (from, node) in &heightmap.nodes {
+        for to in &node.edges {
+            reversed.entry(*to).or_default().push(*from);
+        }
+    }
+    for (position, node) in heightmap.nodes.iter_mut() {
+        node.edges = reversed.remove(position).unwrap_or_default();
+    }
+    let end = heightmap.end;
+    search(heightmap, end, 'a')
+}
+
+fn search(heightmap: Heightmap, source: Position, destination: char) -> Result<usize, SolveError> {
+    let mut search = PathSearch::from_heightmap(heightmap, source, Goal::Height(destination));
+    while search.step() {}
+    search
+        .path()
+        .map(|path| path.len() - 1)
+        .ok_or_else(|| SolveError::invalid(format!("no path to a square {:?}", destination)))
+}
+
+// Where a search stops: at a given square, or at the first square of a kind.
+enum Goal {
+    Square(Position),
+    Height(char),
+}
+
+// Breadth-first search one wave at a time, every step visiting all squares
+// one step further from the source, for the solver and the visualization.
+pub struct PathSearch {
+    heightmap: Heightmap,
+    source: Position,
+    goal: Goal,
+    frontier: Vec<Position>,
+    // The square each visited square was reached from.
+    parents: HashMap<Position, Position>,
+    found: Option<Position>,
+}
+
+impl PathSearch {
+    // Climbs from the start square to the best signal square, like part 1.
+    pub fn new(input: &str) -> Result<Self, SolveError> {
+        let heightmap = parse_input(input)?;
+        let (start, end) = (heightmap.start, heightmap.end);
+        Ok(Self::from_heightmap(heightmap, start, Goal::Square(end)))
+    }
+
+    fn from_heightmap(heightmap: Heightmap, source: Position, goal: Goal) -> Self {
+        let mut search = Self {
+            heightmap,
+            source,
+            goal,
+            frontier: vec![],
+            parents: HashMap::new(),
+            found: None,
+        };
+        search.restart(source);
+        search
+    }
+
+    fn restart(&mut self, source: Position) {
+        self.source = source;
+        self.frontier = vec![source];
+        self.parents = HashMap::from([(source, source)]);
+        self.found = self.reached(source).then_some(source);
+    }
+
+    // Starts over between two other squares, unless either is off the map.
+    pub fn reroute(&mut self, start: Position, end: Position) -> bool {
+        let nodes = &self.heightmap.nodes;
+        if !nodes.contains_key(&start) || !nodes.contains_key(&end) {
+            return false;
+        }
+        self.goal = Goal::Square(end);
+        self.restart(start);
+        true
+    }
+
+    fn reached(&self, square: Position) -> bool {
+        match self.goal {
+            Goal::Square(goal) => square == goal,
+            Goal::Height(height) => self.heightmap.nodes[&square].value == height,
+        }
+    }
+
+    // Visits the next wave, returning false once the goal was found or there
+    // is nowhere left to go.
+    pub fn step(&mut self) -> bool {
+        if self.found.is_some() || self.frontier.is_empty() {
+            return false;
+        }
+        let mut next = vec![];
+        for node in &self.frontier {
+            for edge in &self.heightmap.nodes[node].edges {
+                if let Entry::Vacant(e) = self.parents.entry(*edge) {
+                    e.insert(*node);
+                    next.push(*edge);
+                }
+            }
+        }
+        self.found = next.iter().copied().find(|square| self.reached(*square));
+        self.frontier = next;
+        true
+    }
+
+    pub fn frontier(&self) -> &[Position] {
+        &self.frontier
+    }
+
+    // The shortest path from the source to the goal, both included, once
+    // found.
+    pub fn path(&self) -> Option<Vec<Position>> {
+        let mut path = vec![self.found?];
+        while *path.last().unwrap() != self.source {
+            path.push(self.parents[path.last().unwrap()]);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.found.is_some() || self.frontier.is_empty()
+    }
+
+    pub fn source(&self) -> Position {
+        self.source
+    }
+
+    pub fn target(&self) -> Option<Position> {
+        match self.goal {
+            Goal::Square(square) => Some(square),
+            Goal::Height(_) => None,
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        self.heightmap.size
+    }
+
+    // From 0 for "a" (and the start square) to 25 for "z" (and the best signal
+    // square).
+    pub fn elevation(&self, square: Position) -> Option<u8> {
+        let node = self.heightmap.nodes.get(&square)?;
+        Some(get_value(node.value) as u8 - b'a')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            Sabqponm
+            abcryxxl
+            accszExk
+            acctuvwj
+            abdefghi
+        "};
+        let (part1, part2) = Day12.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "31");
+        assert_eq!(&part2, "29");
+    }
+
+    #[test]
+    fn path_search() {
+        let input = indoc! {"
+            Sabqponm
+            abcryxxl
+            accszExk
+            acctuvwj
+            abdefghi
+        "};
+        let mut search = PathSearch::new(input).unwrap();
+        assert!(search.step());
+        assert_eq!(search.frontier(), [(0, 1), (1, 0)]);
+        while search.step() {}
+        assert_eq!(search.path().unwrap().len(), 32);
+        assert!(search.reroute((0, 0), (2, 0)));
+        while search.step() {}
+        assert_eq!(search.path(), Some(vec![(0, 0), (1, 0), (2, 0)]));
+        assert!(!search.reroute((0, 0), (8, 0)));
+    }
+}
@@ -1,5 +1,10 @@
 use std::{cmp::Ordering, slice::Iter, str::Chars};
 
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
 #[derive(Debug, Clone, PartialEq)]
 enum Content {
     Value(usize),
@@ -31,11 +36,28 @@ impl Content {
     }
 }
 
-pub fn solve(input: &str) -> (String, String) {
-    let part1 = part1(parse_input(input));
-    let part2 = part2(parse_input(input));
+pub struct Day13;
+
+impl Solver for Day13 {
+    fn day(&self) -> usize {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Distress Signal"
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
 }
 
 fn parse_packet(packet: &mut Chars) -> Vec<Content> {
@@ -60,26 +82,58 @@ fn parse_packet(packet: &mut Chars) -> Vec<Content> {
     values
 }
 
-fn parse_input(input: &str) -> Vec<Content> {
-    let input = input.lines().collect::<Vec<&str>>().join("|");
-    input
-        .split("||")
-        .flat_map(|x| {
-            x.split('|')
-                .flat_map(|y| parse_packet(&mut y.chars()))
-                .collect::<Vec<Content>>()
-        })
-        .collect()
+fn validate_packet(line: &Line) -> Result<(), SolveError> {
+    let mut depth = 0;
+    for (idx, c) in line.text.char_indices() {
+        if depth == 0 && idx > 0 {
+            return Err(SolveError::at(
+                line.number,
+                idx + 1,
+                "unexpected data after the packet",
+            ));
+        }
+        match c {
+            '[' => depth += 1,
+            ']' if depth > 0 => depth -= 1,
+            '0'..='9' | ',' if depth > 0 => {}
+            _ => {
+                return Err(SolveError::at(
+                    line.number,
+                    idx + 1,
+                    format!("unexpected {:?}", c),
+                ))
+            }
+        }
+    }
+    if depth > 0 {
+        return Err(line.expected(None, "\"]\""));
+    }
+    Ok(())
 }
 
-fn part1(pairs: Vec<Content>) -> usize {
+fn parse_input(input: &str) -> Result<Vec<Content>, SolveError> {
+    let mut packets = vec![];
+    for line in lines(input) {
+        if line.text.is_empty() {
+            continue;
+        }
+        validate_packet(&line)?;
+        packets.extend(parse_packet(&mut line.text.chars()));
+    }
+    Ok(packets)
+}
+
+fn part1(pairs: Vec<Content>) -> Result<usize, SolveError> {
+    if !pairs.len().is_multiple_of(2) {
+        return Err(SolveError::invalid("expected the packets to come in pairs"));
+    }
     let mut sum = 0;
     for (idx, pair) in pairs.chunks(2).enumerate() {
         if pair[0].cmp(&pair[1]) == Ordering::Less {
             sum += idx + 1;
         }
     }
-    sum
+    Ok(sum)
 }
 
 fn part2(mut pairs: Vec<Content>) -> usize {
@@ -129,7 +183,7 @@ mod tests {
             [1,[2,[3,[4,[5,6,7]]]],8,9]
             [1,[2,[3,[4,[5,6,0]]]],8,9]
         "};
-        let (part1, part2) = solve(input);
+        let (part1, part2) = Day13.solve(input, &Parameters::default()).unwrap();
 
         assert_eq!(&part1, "13");
         assert_eq!(&part2, "140");
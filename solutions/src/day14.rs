@@ -0,0 +1,291 @@
+use std::{collections::HashSet, num::ParseIntError};
+
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+// Real inputs keep their rocks around x=500 and less than 180 deep. Paths are
+// traced one position at a time and the floor of part 2 fills with a triangle
+// of sand as deep as the cave, so both need a bound.
+const MAX_X: isize = 10_000;
+const MAX_DEPTH: isize = 200;
+
+#[derive(Clone)]
+struct Cave {
+    rocks: HashSet<Position>,
+    max_depth: isize,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct Position {
+    x: isize,
+    y: isize,
+}
+
+impl Cave {
+    fn new() -> Self {
+        Self {
+            rocks: HashSet::new(),
+            max_depth: 0,
+        }
+    }
+
+    fn insert(&mut self, value: Position) {
+        if value.y > self.max_depth {
+            self.max_depth = value.y;
+        }
+        self.rocks.insert(value);
+    }
+
+    fn contains(&self, value: &Position) -> bool {
+        self.rocks.contains(value)
+    }
+
+    fn find_next(&self, from: &mut Position) -> bool {
+        let mut tmp = *from;
+        tmp.y += 1;
+        if !self.contains(&tmp) {
+            *from = tmp;
+            return true;
+        }
+        tmp.x -= 1;
+        if !self.contains(&tmp) {
+            *from = tmp;
+            return true;
+        }
+        tmp.x += 2;
+        if !self.contains(&tmp) {
+            *from = tmp;
+            return true;
+        }
+        false
+    }
+}
+
+// What a call to `SandFall::step` did: the grain of sand fell to a new
+// position, or came to rest there. Once sand flows into the void, or the last
+// grain blocks the source, every step is `Done`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Fall(isize, isize),
+    Rest(isize, isize),
+    Done,
+}
+
+// The cave filling up one move of a grain at a time, for the solver and the
+// visualization. Without a floor, as in part 1, sand falling below the lowest
+// rock ends it. With the floor of part 2, two rows below the lowest rock, it
+// ends when the sand reaches the source.
+pub struct SandFall {
+    cave: Cave,
+    floor: bool,
+    sand: Position,
+    rested: usize,
+    done: bool,
+}
+
+impl SandFall {
+    pub fn new(input: &str, floor: bool) -> Result<Self, SolveError> {
+        Ok(Self::from_cave(parse_input(input)?, floor))
+    }
+
+    fn from_cave(cave: Cave, floor: bool) -> Self {
+        Self {
+            cave,
+            floor,
+            sand: Position::new(500, 0),
+            rested: 0,
+            done: false,
+        }
+    }
+
+    pub fn step(&mut self) -> Step {
+        if self.done {
+            return Step::Done;
+        }
+        if !self.floor && self.sand.y > self.cave.max_depth {
+            self.done = true;
+            return Step::Done;
+        }
+        if !(self.floor && self.sand.y == self.cave.max_depth + 1)
+            && self.cave.find_next(&mut self.sand)
+        {
+            return Step::Fall(self.sand.x, self.sand.y);
+        }
+        let rest = self.sand;
+        self.cave.rocks.insert(rest);
+        self.rested += 1;
+        self.sand = Position::new(500, 0);
+        self.done = rest == self.sand;
+        Step::Rest(rest.x, rest.y)
+    }
+
+    // The rocks, along with the sand that came to rest so far.
+    pub fn blocked(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.cave.rocks.iter().map(|pos| (pos.x, pos.y))
+    }
+
+    pub fn max_depth(&self) -> isize {
+        self.cave.max_depth
+    }
+
+    pub fn rested(&self) -> usize {
+        self.rested
+    }
+
+    pub fn done(&self) -> bool {
+        self.done
+    }
+}
+
+impl std::str::FromStr for Position {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',');
+        Ok(Self::new(
+            parts.next().unwrap_or_default().parse()?,
+            parts.next().unwrap_or_default().parse()?,
+        ))
+    }
+}
+
+impl Position {
+    fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    fn day(&self) -> usize {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Regolith Reservoir"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
+}
+
+fn parse_group(group: &Line, cave: &mut Cave) -> Result<(), SolveError> {
+    let mut previous = None;
+    for edge in group.text.split(" -> ") {
+        let token = edge;
+        let edge = group.parse::<Position>(token)?;
+        if !(-MAX_X..=MAX_X).contains(&edge.x) || !(0..=MAX_DEPTH).contains(&edge.y) {
+            return Err(group.error(
+                token,
+                format!(
+                    "rocks must be within x=-{} to {} and y=0 to {}",
+                    MAX_X, MAX_X, MAX_DEPTH
+                ),
+            ));
+        }
+        cave.insert(edge);
+        if let Some(mut previous) = previous {
+            while previous != edge {
+                cave.insert(previous);
+                previous.x -= previous.x.cmp(&edge.x) as isize;
+                previous.y -= previous.y.cmp(&edge.y) as isize;
+            }
+        }
+        previous = Some(edge);
+    }
+    Ok(())
+}
+
+fn parse_input(input: &str) -> Result<Cave, SolveError> {
+    let mut cave = Cave::new();
+    for group in lines(input) {
+        parse_group(&group, &mut cave)?;
+    }
+    Ok(cave)
+}
+
+fn part1(cave: Cave) -> usize {
+    let mut fall = SandFall::from_cave(cave, false);
+    while fall.step() != Step::Done {}
+    fall.rested()
+}
+
+fn part2(cave: Cave) -> usize {
+    let mut fall = SandFall::from_cave(cave, true);
+    while fall.step() != Step::Done {}
+    fall.rested()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            498,4 -> 498,6 -> 496,6
+            503,4 -> 502,4 -> 502,9 -> 494,9
+        "};
+        let (part1, part2) = Day14.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "24");
+        assert_eq!(&part2, "93");
+    }
+
+    #[test]
+    fn bounds() {
+        // Tracing this path inserted every position down to it.
+        let input = "500,2 -> 500,9223372036854775807\n";
+        assert_eq!(
+            Day14.solve(input, &Parameters::default()).unwrap_err(),
+            SolveError::at(
+                1,
+                10,
+                "rocks must be within x=-10000 to 10000 and y=0 to 200"
+            )
+        );
+        let input = "10000,200 -> 9990,200\n";
+        assert_eq!(
+            Day14.solve_part(1, input, &Parameters::default()).unwrap(),
+            "0"
+        );
+    }
+
+    #[test]
+    fn sand_fall() {
+        let input = indoc! {"
+            498,4 -> 498,6 -> 496,6
+            503,4 -> 502,4 -> 502,9 -> 494,9
+        "};
+        let mut fall = SandFall::new(input, false).unwrap();
+        let steps: Vec<Step> = (0..10).map(|_| fall.step()).collect();
+        assert_eq!(
+            steps,
+            [
+                Step::Fall(500, 1),
+                Step::Fall(500, 2),
+                Step::Fall(500, 3),
+                Step::Fall(500, 4),
+                Step::Fall(500, 5),
+                Step::Fall(500, 6),
+                Step::Fall(500, 7),
+                Step::Fall(500, 8),
+                Step::Rest(500, 8),
+                Step::Fall(500, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::{cmp, collections::HashSet, num::ParseIntError};
+
+use regex::Regex;
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameter, Parameters, Parsed, Solver},
+};
+
+const HALF_GRID: Parameter = Parameter {
+    name: "half_grid",
+    description: "Row to inspect in part 1, half the search area of part 2. \
+                  Only 10 (the example) and 2000000 (real inputs) are detected, \
+                  from sensors reaching past the row but not past twice it. \
+                  Other inputs need it set.",
+    default: 2000000,
+};
+
+// The example in the puzzle text searches 0..=20, real inputs 0..=4000000.
+// Nothing in the input gives the row away, so these are the only two detected,
+// each for sensors reaching further than the row but within the search area.
+const HALF_GRIDS: [i64; 2] = [10, 2000000];
+// Real inputs stay within a few million. Beyond this, distances and tuning
+// frequencies would no longer fit in 64 bits.
+const MAX_COORDINATE: i64 = 1 << 32;
+// Part 1 marks every position of the row a sensor covers, real inputs cover
+// about 30 million counting overlaps.
+const MAX_ROW_COVER: i64 = 100_000_000;
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl Position {
+    fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    fn get_distance(&self, beacon: &Self) -> i64 {
+        (self.x - beacon.x).abs() + (self.y - beacon.y).abs()
+    }
+}
+
+impl std::str::FromStr for Position {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(", y=");
+        let x = parts.next().unwrap_or_default().parse()?;
+        let y = parts.next().unwrap_or_default().parse()?;
+        Ok(Position::new(x, y))
+    }
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    fn day(&self) -> usize {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Beacon Exclusion Zone"
+    }
+
+    fn params(&self) -> &'static [Parameter] {
+        &[HALF_GRID]
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, params: &Parameters) -> Result<String, SolveError> {
+        let sensors: Vec<_> = unpack(parsed)?;
+        let half_grid = half_grid(&sensors, params)?;
+        Ok(part1(sensors, half_grid)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, params: &Parameters) -> Result<String, SolveError> {
+        let sensors: Vec<_> = unpack(parsed)?;
+        let half_grid = half_grid(&sensors, params)?;
+        Ok(part2(sensors, 2 * half_grid)?.to_string())
+    }
+}
+
+fn half_grid(sensors: &[(Position, Position)], params: &Parameters) -> Result<i64, SolveError> {
+    if let Some(half_grid) = params.given(&HALF_GRID) {
+        if !(0..=MAX_COORDINATE).contains(&half_grid) {
+            return Err(SolveError::invalid(format!(
+                "half_grid must be between 0 and {}",
+                MAX_COORDINATE
+            )));
+        }
+        return Ok(half_grid);
+    }
+    let extent = sensors
+        .iter()
+        .map(|(sensor, _)| sensor.x.unsigned_abs().max(sensor.y.unsigned_abs()))
+        .max()
+        .unwrap_or_default();
+    HALF_GRIDS
+        .into_iter()
+        .find(|half_grid| (*half_grid as u64 + 1..=2 * *half_grid as u64).contains(&extent))
+        .ok_or_else(|| {
+            SolveError::invalid(format!(
+                "sensors reach {}, like neither the example (11 to 20) nor real inputs \
+                (2000001 to 4000000), so half_grid must be set",
+                extent
+            ))
+        })
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Position, Position)>, SolveError> {
+    let mut res = vec![];
+    let re = Regex::new(r"(-?\d+, .=-?\d+)").unwrap();
+    for line in lines(input) {
+        let mut positions = re.find_iter(line.text).map(|x| x.as_str());
+        let scanner: Position = line.parse_next(&mut positions, "a sensor position")?;
+        let beacon: Position = line.parse_next(&mut positions, "a beacon position")?;
+        for position in [scanner, beacon] {
+            if position.x.unsigned_abs().max(position.y.unsigned_abs()) > MAX_COORDINATE as u64 {
+                return Err(SolveError::at(
+                    line.number,
+                    1,
+                    format!("coordinates must be within ±{}", MAX_COORDINATE),
+                ));
+            }
+        }
+        res.push((scanner, beacon));
+    }
+    Ok(res)
+}
+
+fn part1(input: Vec<(Position, Position)>, half_grid: i64) -> Result<usize, SolveError> {
+    let cover: i64 = input
+        .iter()
+        .map(|(scanner, beacon)| {
+            let half_width = scanner.get_distance(beacon) - (half_grid - scanner.y).abs();
+            cmp::max(0, 2 * half_width + 1)
+        })
+        .sum();
+    if cover > MAX_ROW_COVER {
+        return Err(SolveError::invalid(format!(
+            "the sensors cover more than {} positions of row {}",
+            MAX_ROW_COVER, half_grid
+        )));
+    }
+    let mut occupied: HashSet<i64> = HashSet::new();
+    let mut half_grid_beacons = HashSet::new();
+    for (scanner, beacon) in &input {
+        if beacon.y == half_grid {
+            half_grid_beacons.insert(beacon);
+        }
+        let distance = scanner.get_distance(beacon);
+        let lower_bound = scanner.y - distance;
+        let higher_bound = scanner.y + distance;
+        if lower_bound <= half_grid && half_grid <= higher_bound {
+            let half_width = ((half_grid - scanner.y).abs() - distance).abs();
+            for x in -half_width..=half_width {
+                occupied.insert(scanner.x + x);
+            }
+        }
+    }
+    Ok(occupied.len() - half_grid_beacons.len())
+}
+
+fn part2(input: Vec<(Position, Position)>, full_grid: i64) -> Result<i64, SolveError> {
+    for (scanner, beacon) in &input {
+        let distance = scanner.get_distance(beacon) + 1;
+        let lower_bound = cmp::max(0, scanner.x - distance);
+        let higher_bound = cmp::min(full_grid, scanner.x + distance);
+        for x in lower_bound..=higher_bound {
+            'outer: for j in [-1, 1] {
+                let edge = Position::new(x, scanner.y + j * (distance - (x - scanner.x).abs()));
+                if edge.x >= 0 && edge.x <= full_grid && edge.y >= 0 && edge.y <= full_grid {
+                    for (other_scanner, other_beacon) in &input {
+                        if other_scanner.get_distance(&edge)
+                            <= other_scanner.get_distance(other_beacon)
+                        {
+                            continue 'outer;
+                        }
+                    }
+                    return Ok(edge.x * 4000000 + edge.y);
+                }
+            }
+        }
+    }
+    Err(SolveError::invalid(
+        "no position for the distress beacon found",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+            Sensor at x=9, y=16: closest beacon is at x=10, y=16
+            Sensor at x=13, y=2: closest beacon is at x=15, y=3
+            Sensor at x=12, y=14: closest beacon is at x=10, y=16
+            Sensor at x=10, y=20: closest beacon is at x=10, y=16
+            Sensor at x=14, y=17: closest beacon is at x=10, y=16
+            Sensor at x=8, y=7: closest beacon is at x=2, y=10
+            Sensor at x=2, y=0: closest beacon is at x=2, y=10
+            Sensor at x=0, y=11: closest beacon is at x=2, y=10
+            Sensor at x=20, y=14: closest beacon is at x=25, y=17
+            Sensor at x=17, y=20: closest beacon is at x=21, y=22
+            Sensor at x=16, y=7: closest beacon is at x=15, y=3
+            Sensor at x=14, y=3: closest beacon is at x=15, y=3
+            Sensor at x=20, y=1: closest beacon is at x=15, y=3
+        "};
+        let mut params = Parameters::default();
+        params.set("half_grid", 10);
+        let (part1, part2) = Day15.solve(input, &params).unwrap();
+
+        assert_eq!(&part1, "26");
+        assert_eq!(&part2, "56000011");
+        assert_eq!(
+            Day15.solve(input, &Parameters::default()).unwrap(),
+            (part1, part2)
+        );
+    }
+
+    #[test]
+    fn half_grid_presets() {
+        let input = "Sensor at x=4000001, y=0: closest beacon is at x=4000000, y=0\n";
+        assert_eq!(
+            Day15.solve(input, &Parameters::default()).unwrap_err(),
+            SolveError::invalid(
+                "sensors reach 4000001, like neither the example (11 to 20) nor real inputs \
+                (2000001 to 4000000), so half_grid must be set"
+            )
+        );
+        let input = "Sensor at x=100, y=0: closest beacon is at x=101, y=0\n";
+        assert!(Day15.solve(input, &Parameters::default()).is_err());
+        let mut params = Parameters::default();
+        params.set("half_grid", 2000001);
+        assert_eq!(Day15.solve_part(1, input, &params).unwrap(), "0");
+        params.set("half_grid", i64::MAX);
+        assert_eq!(
+            Day15.solve(input, &params).unwrap_err(),
+            SolveError::invalid("half_grid must be between 0 and 4294967296")
+        );
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // The distance between sensor and beacon overflowed.
+        let input = "Sensor at x=9223372036854775807, y=0: closest beacon is at x=-9223372036854775808, y=0\n";
+        assert_eq!(
+            Day15.solve(input, &Parameters::default()).unwrap_err(),
+            SolveError::at(1, 1, "coordinates must be within ±4294967296")
+        );
+        // Part 1 marked all 2^34 positions a sensor this far out covers.
+        let input = "Sensor at x=0, y=2000000: closest beacon is at x=4294967296, y=4294967296\n";
+        let mut params = Parameters::default();
+        params.set("half_grid", 2000000);
+        assert_eq!(
+            Day15.solve_part(1, input, &params).unwrap_err(),
+            SolveError::invalid("the sensors cover more than 100000000 positions of row 2000000")
+        );
+    }
+}
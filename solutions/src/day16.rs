@@ -1,60 +1,65 @@
-use std::{
-    collections::{HashMap, HashSet},
-    num::ParseIntError,
-};
+use std::collections::{HashMap, HashSet};
 
 use regex::Regex;
 
-#[derive(Debug)]
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+#[derive(Debug, Clone)]
 struct Tunnel {
     nodes: HashMap<String, Node>,
 }
 
 impl std::str::FromStr for Tunnel {
-    type Err = ParseIntError;
+    type Err = SolveError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let re = Regex::new(r"([A-Z]{2})").unwrap();
         let mut nodes = HashMap::new();
-        for line in input.lines() {
+        for line in lines(input) {
             let valve = re
-                .captures(line)
-                .unwrap()
-                .get(0)
-                .unwrap()
+                .find(line.text)
+                .ok_or_else(|| line.expected(None, "a valve name"))?
                 .as_str()
                 .to_owned();
-            let node = line.parse()?;
+            let node = Node::parse(&line)?;
             nodes.insert(valve, node);
         }
+        if !nodes.contains_key("AA") {
+            return Err(SolveError::invalid("expected a valve \"AA\" to start from"));
+        }
+        for (valve, node) in &nodes {
+            if let Some(adjacency) = node.adjacencies.iter().find(|x| !nodes.contains_key(*x)) {
+                return Err(SolveError::invalid(format!(
+                    "valve {} leads to unknown valve {}",
+                    valve, adjacency
+                )));
+            }
+        }
         Ok(Self { nodes })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Node {
     value: isize,
     adjacencies: HashSet<String>,
 }
 
-impl std::str::FromStr for Node {
-    type Err = ParseIntError;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
+impl Node {
+    fn parse(line: &Line) -> Result<Self, SolveError> {
         let re_value = Regex::new(r"(\d+)").unwrap();
         let re_adjacencies = Regex::new(r"([A-Z]{2})(,\s[A-Z]{2})*$").unwrap();
         let value = re_value
-            .captures(input)
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<isize>()?;
+            .find(line.text)
+            .ok_or_else(|| line.expected(None, "a flow rate"))?
+            .as_str();
+        let value = line.parse::<isize>(value)?;
         let adjacencies = re_adjacencies
-            .captures(input)
-            .unwrap()
-            .get(0)
-            .unwrap()
+            .find(line.text)
+            .ok_or_else(|| line.expected(None, "a list of tunnels"))?
             .as_str()
             .split(", ")
             .map(|x| x.to_owned())
@@ -63,15 +68,32 @@ impl std::str::FromStr for Node {
     }
 }
 
-pub fn solve(input: &str) -> (String, String) {
-    let part1 = part1(parse_input(input));
-    let part2 = part2(parse_input(input));
+pub struct Day16;
 
-    (part1.to_string(), part2.to_string())
+impl Solver for Day16 {
+    fn day(&self) -> usize {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Proboscidea Volcanium"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
 }
 
-fn parse_input(input: &str) -> Tunnel {
-    input.parse().unwrap()
+fn parse_input(input: &str) -> Result<Tunnel, SolveError> {
+    input.parse()
 }
 
 #[allow(clippy::type_complexity)]
@@ -198,7 +220,7 @@ mod tests {
             Valve II has flow rate=0; tunnels lead to valves AA, JJ
             Valve JJ has flow rate=21; tunnel leads to valve II
         "};
-        let (part1, part2) = solve(input);
+        let (part1, part2) = Day16.solve(input, &Parameters::default()).unwrap();
 
         assert_eq!(&part1, "1651");
         assert_eq!(&part2, "1706");
@@ -0,0 +1,339 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const SHAPES: [&str; 5] = ["####", ".#.|###|.#.", "###|..#|..#", "#|#|#|#", "##|##"];
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl Position {
+    fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    fn add(&self, x: i64, y: i64) -> Self {
+        Self::new(self.x + x, self.y + y)
+    }
+}
+
+#[derive(Debug)]
+struct Shape {
+    positions: HashSet<Position>,
+}
+
+impl Shape {
+    fn new(shape: &str, origin: Position) -> Self {
+        let mut positions = HashSet::new();
+        for (y, line) in shape.split('|').enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '.' {
+                    continue;
+                }
+                positions.insert(Position::new(origin.x + x as i64, origin.y + y as i64));
+            }
+        }
+        Self { positions }
+    }
+}
+
+#[derive(Debug)]
+struct Playground {
+    top: i64,
+    left: i64,
+    right: i64,
+    map: HashSet<Position>,
+}
+
+impl Playground {
+    fn new() -> Self {
+        Self {
+            top: 0,
+            left: 0,
+            right: 8,
+            map: HashSet::new(),
+        }
+    }
+
+    fn push(&self, dir: &char, shape: &mut Shape) {
+        let dir: i64 = if *dir == '>' { 1 } else { -1 };
+        let mut new_shape = Shape {
+            positions: HashSet::new(),
+        };
+        for pos in &shape.positions {
+            let new_pos = pos.add(dir, 0);
+            if new_pos.x <= self.left || new_pos.x >= self.right {
+                return;
+            }
+            if self.map.contains(&new_pos) {
+                return;
+            }
+            new_shape.positions.insert(new_pos);
+        }
+        *shape = new_shape;
+    }
+
+    fn move_down(&self, shape: &mut Shape) -> bool {
+        let mut new_shape = Shape {
+            positions: HashSet::new(),
+        };
+        for pos in &shape.positions {
+            let new_pos = pos.add(0, -1);
+            if self.map.contains(&new_pos) || new_pos.y <= 0 {
+                return true;
+            }
+            new_shape.positions.insert(new_pos);
+        }
+        *shape = new_shape;
+        false
+    }
+
+    fn fix_shape(&mut self, shape: &Shape) {
+        for pos in &shape.positions {
+            if pos.y > self.top {
+                self.top = pos.y;
+            }
+            self.map.insert(*pos);
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    fn day(&self) -> usize {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Pyroclastic Flow"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<char>, SolveError> {
+    let line = lines(input)
+        .next()
+        .ok_or_else(|| SolveError::invalid("expected a jet pattern"))?;
+    if let Some(jet) = line.text.matches(|c| c != '<' && c != '>').next() {
+        return Err(line.expected(Some(jet), "\"<\" or \">\""));
+    }
+    if line.text.is_empty() {
+        return Err(line.expected(None, "\"<\" or \">\""));
+    }
+    Ok(line.text.chars().collect())
+}
+
+// What a call to `RockFall::step` did: the jet pushed the rock, the rock fell
+// a row, or it came to rest on the given positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Push(char),
+    Fall,
+    Settle(Vec<(i64, i64)>),
+}
+
+// The pattern part 2 skips over: every `rocks` rocks the tower grows by
+// `height` rows, and the repetition that gave it away ends at row `top`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub rocks: i64,
+    pub height: i64,
+    pub top: i64,
+}
+
+// The chamber played one push or fall at a time, for the solver and the
+// visualization. Columns run from 1 to 7 and the floor is row 0.
+pub struct RockFall {
+    directions: Vec<char>,
+    jets: usize,
+    shapes: usize,
+    playground: Playground,
+    shape: Shape,
+    pushed: bool,
+    rocks: i64,
+    // Steps since the last rock settled and the most any rock took, which
+    // with the jet and the shape tells apart the states of the cycle detector.
+    height: usize,
+    max_height: usize,
+    seen: HashMap<(usize, usize, usize), (i64, i64)>,
+    cycle: Option<Cycle>,
+}
+
+impl RockFall {
+    pub fn new(input: &str) -> Result<Self, SolveError> {
+        Ok(Self::from_directions(parse_input(input)?))
+    }
+
+    fn from_directions(directions: Vec<char>) -> Self {
+        let playground = Playground::new();
+        let shape = Shape::new(SHAPES[0], Position::new(3, playground.top + 4));
+        Self {
+            directions,
+            jets: 0,
+            shapes: 0,
+            playground,
+            shape,
+            pushed: false,
+            rocks: 0,
+            height: 0,
+            max_height: 0,
+            seen: HashMap::new(),
+            cycle: None,
+        }
+    }
+
+    pub fn step(&mut self) -> Step {
+        if !self.pushed {
+            let direction = self.directions[self.jets % self.directions.len()];
+            self.playground.push(&direction, &mut self.shape);
+            self.jets += 1;
+            self.pushed = true;
+            return Step::Push(direction);
+        }
+        self.pushed = false;
+        let step = if self.playground.move_down(&mut self.shape) {
+            Step::Settle(self.settle())
+        } else {
+            Step::Fall
+        };
+        self.height += 1;
+        step
+    }
+
+    fn settle(&mut self) -> Vec<(i64, i64)> {
+        let rock = self.rock();
+        self.max_height = self.max_height.max(self.height);
+        self.height = 0;
+        self.playground.fix_shape(&self.shape);
+        self.shapes = (self.shapes + 1) % SHAPES.len();
+        self.shape = Shape::new(
+            SHAPES[self.shapes],
+            Position::new(3, self.playground.top + 4),
+        );
+        self.rocks += 1;
+        if self.cycle.is_none() {
+            let key = (
+                (self.jets - 1) % self.directions.len(),
+                self.shapes,
+                self.max_height,
+            );
+            if let Some((rocks, top)) = self.seen.get(&key) {
+                self.cycle = Some(Cycle {
+                    rocks: self.rocks - rocks,
+                    height: self.playground.top - top,
+                    top: self.playground.top,
+                });
+            }
+            self.seen.insert(key, (self.rocks, self.playground.top));
+        }
+        rock
+    }
+
+    pub fn rock(&self) -> Vec<(i64, i64)> {
+        let mut rock: Vec<_> = self
+            .shape
+            .positions
+            .iter()
+            .map(|pos| (pos.x, pos.y))
+            .collect();
+        rock.sort();
+        rock
+    }
+
+    pub fn rocks(&self) -> i64 {
+        self.rocks
+    }
+
+    pub fn top(&self) -> i64 {
+        self.playground.top
+    }
+
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+}
+
+fn part1(directions: Vec<char>) -> i64 {
+    let mut fall = RockFall::from_directions(directions);
+    while fall.rocks() < 2022 {
+        fall.step();
+    }
+    fall.top()
+}
+
+// Once the cycle shows up, the rocks of all but the last few repetitions are
+// counted without dropping them.
+fn part2(directions: Vec<char>) -> i64 {
+    const ROCKS: i64 = 1000000000000;
+    let mut fall = RockFall::from_directions(directions);
+    let mut skipped = None;
+    loop {
+        if !matches!(fall.step(), Step::Settle(_)) {
+            continue;
+        }
+        let (rocks, height) = skipped.unwrap_or((0, 0));
+        if fall.rocks() + rocks >= ROCKS {
+            return fall.top() + height;
+        }
+        if let (None, Some(cycle)) = (skipped, fall.cycle()) {
+            let cycles = ROCKS / cycle.rocks - 2;
+            skipped = Some((cycles * cycle.rocks, cycles * cycle.height));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        let (part1, part2) = Day17.solve(input, &Parameters::default()).unwrap();
+        assert_eq!(&part1, "3068");
+        assert_eq!(&part2, "1514285714288");
+    }
+
+    #[test]
+    fn rock_fall() {
+        let mut fall = RockFall::new(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+        let steps: Vec<Step> = (0..8).map(|_| fall.step()).collect();
+        assert_eq!(
+            steps,
+            [
+                Step::Push('>'),
+                Step::Fall,
+                Step::Push('>'),
+                Step::Fall,
+                Step::Push('>'),
+                Step::Fall,
+                Step::Push('<'),
+                Step::Settle(vec![(3, 1), (4, 1), (5, 1), (6, 1)]),
+            ]
+        );
+        assert_eq!((fall.rocks(), fall.top()), (1, 1));
+        while fall.cycle().is_none() {
+            fall.step();
+        }
+        assert_eq!(fall.cycle().unwrap().rocks, 35);
+        assert_eq!(fall.cycle().unwrap().height, 53);
+    }
+}
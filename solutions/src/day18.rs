@@ -0,0 +1,311 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    num::ParseIntError,
+};
+
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+// Part 2 walks every cell of the box around the droplet, real inputs need
+// about 10000.
+const MAX_VOLUME: u128 = 1_000_000;
+
+const SIDES: [Position; 6] = [
+    Position { x: -1, y: 0, z: 0 },
+    Position { x: 1, y: 0, z: 0 },
+    Position { x: 0, y: -1, z: 0 },
+    Position { x: 0, y: 1, z: 0 },
+    Position { x: 0, y: 0, z: -1 },
+    Position { x: 0, y: 0, z: 1 },
+];
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+struct Position {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Position {
+    fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    fn adjacent(&self, rhs: &Self) -> bool {
+        let mut diff = [
+            self.x.abs_diff(rhs.x),
+            self.y.abs_diff(rhs.y),
+            self.z.abs_diff(rhs.z),
+        ];
+        diff.sort_unstable();
+        diff == [0, 0, 1]
+    }
+
+    // None past the edge of the 64 bit range.
+    fn add(&self, rhs: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+            z: self.z.checked_add(rhs.z)?,
+        })
+    }
+
+    fn max(&self, rhs: &Self) -> Self {
+        Self {
+            x: std::cmp::max(self.x, rhs.x),
+            y: std::cmp::max(self.y, rhs.y),
+            z: std::cmp::max(self.z, rhs.z),
+        }
+    }
+
+    fn min(&self, rhs: &Self) -> Self {
+        Self {
+            x: std::cmp::min(self.x, rhs.x),
+            y: std::cmp::min(self.y, rhs.y),
+            z: std::cmp::min(self.z, rhs.z),
+        }
+    }
+}
+
+struct World {
+    cubes: HashSet<Position>,
+    max: Position,
+    min: Position,
+}
+
+impl World {
+    fn new(cubes: HashSet<Position>) -> Self {
+        let max = Position::new(i64::MIN, i64::MIN, i64::MIN);
+        let min = Position::new(i64::MAX, i64::MAX, i64::MAX);
+        Self { cubes, max, min }
+    }
+
+    fn contains(&self, cube: &Position) -> bool {
+        self.cubes.contains(cube)
+    }
+
+    fn total_surface_area(&mut self) -> i64 {
+        let mut sum = 0;
+        for lhs_cube in &self.cubes {
+            let mut current = 6;
+            self.max = self.max.max(lhs_cube);
+            self.min = self.min.min(lhs_cube);
+            for rhs_cube in &self.cubes {
+                if lhs_cube == rhs_cube {
+                    continue;
+                }
+                if lhs_cube.adjacent(rhs_cube) {
+                    current -= 1;
+                }
+                if current <= 0 {
+                    break;
+                }
+            }
+            sum += current;
+        }
+        sum
+    }
+}
+
+impl std::str::FromStr for Position {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = s.split(',');
+        let x = s.next().unwrap_or_default().parse()?;
+        let y = s.next().unwrap_or_default().parse()?;
+        let z = s.next().unwrap_or_default().parse()?;
+        Ok(Self { x, y, z })
+    }
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    fn day(&self) -> usize {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "Boiling Boulders"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashSet<Position>, SolveError> {
+    let cubes: HashSet<Position> = lines(input)
+        .map(|line| line.parse(line.text))
+        .collect::<Result<_, _>>()?;
+    if cubes.is_empty() {
+        return Err(SolveError::invalid("expected at least one cube"));
+    }
+    Ok(cubes)
+}
+
+fn part1(cubes: HashSet<Position>) -> i64 {
+    let mut world = World::new(cubes);
+    world.total_surface_area()
+}
+
+fn part2(cubes: HashSet<Position>) -> Result<i64, SolveError> {
+    let mut world = World::new(cubes);
+    let mut sum = world.total_surface_area();
+    // The steam flows around the droplet, so it needs room on every side.
+    let (max, min) = match (
+        world.max.add(&Position::new(1, 1, 1)),
+        world.min.add(&Position::new(-1, -1, -1)),
+    ) {
+        (Some(max), Some(min)) => (max, min),
+        _ => {
+            return Err(SolveError::invalid(
+                "the droplet reaches the edge of the 64 bit range",
+            ))
+        }
+    };
+    let volume = [(min.x, max.x), (min.y, max.y), (min.z, max.z)]
+        .iter()
+        .map(|&(min, max)| (i128::from(max) - i128::from(min) + 1) as u128)
+        .try_fold(1u128, |volume, side| volume.checked_mul(side));
+    match volume {
+        Some(volume) if volume <= MAX_VOLUME => {}
+        _ => {
+            return Err(SolveError::invalid(format!(
+                "the box around the droplet holds more than {} cubes",
+                MAX_VOLUME
+            )))
+        }
+    }
+    world.max = max;
+    world.min = min;
+
+    let mut empty_cubes = HashSet::new();
+
+    for x in world.min.x..=world.max.x {
+        for y in world.min.y..=world.max.y {
+            for z in world.min.z..=world.max.z {
+                let new_pos = Position::new(x, y, z);
+                if !world.contains(&new_pos) {
+                    empty_cubes.insert(new_pos);
+                }
+            }
+        }
+    }
+
+    let mut adjacencies: HashMap<Position, Vec<Position>> = HashMap::new();
+
+    for cube in &empty_cubes {
+        let mut cube_adjacencies = vec![];
+        for side in SIDES {
+            match cube.add(&side) {
+                Some(new_pos) if empty_cubes.contains(&new_pos) => cube_adjacencies.push(new_pos),
+                _ => {}
+            }
+        }
+        adjacencies.insert(*cube, cube_adjacencies);
+    }
+
+    let mut accessable = HashSet::new();
+    bfs(&world.min, &adjacencies, &mut accessable);
+
+    for cube in empty_cubes {
+        if !accessable.contains(&cube) {
+            for occupied_cube in &world.cubes {
+                if cube.adjacent(occupied_cube) {
+                    sum -= 1;
+                }
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+fn bfs(
+    current: &Position,
+    adjacencies: &HashMap<Position, Vec<Position>>,
+    accessable: &mut HashSet<Position>,
+) {
+    let mut queue = VecDeque::from([*current]);
+    accessable.insert(*current);
+    while let Some(current) = queue.pop_front() {
+        for adjacency in adjacencies.get(&current).unwrap() {
+            if accessable.insert(*adjacency) {
+                queue.push_back(*adjacency);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            2,2,2
+            1,2,2
+            3,2,2
+            2,1,2
+            2,3,2
+            2,2,1
+            2,2,3
+            2,2,4
+            2,2,6
+            1,2,5
+            3,2,5
+            2,1,5
+            2,3,5
+        "};
+        let (part1, part2) = Day18.solve(input, &Parameters::default()).unwrap();
+        assert_eq!(&part1, "64");
+        assert_eq!(&part2, "58");
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // Growing the search box around the droplet overflowed.
+        let input = "9223372036854775807,0,0\n";
+        assert_eq!(
+            Day18.solve_part(1, input, &Parameters::default()).unwrap(),
+            "6"
+        );
+        assert_eq!(
+            Day18
+                .solve_part(2, input, &Parameters::default())
+                .unwrap_err(),
+            SolveError::invalid("the droplet reaches the edge of the 64 bit range")
+        );
+        // So did telling whether two cubes touch.
+        let input = "9223372036854775807,0,0\n-9223372036854775808,0,0\n";
+        assert_eq!(
+            Day18.solve_part(1, input, &Parameters::default()).unwrap(),
+            "12"
+        );
+        // Flooding a box this size recursively overflowed the stack.
+        let input = "0,0,0\n60,60,60";
+        assert_eq!(Day18.solve(input, &Parameters::default()).unwrap().1, "12");
+        // And the box can't be allowed to grow with the coordinates.
+        let input = "0,0,0\n1000,1000,1000";
+        assert_eq!(
+            Day18
+                .solve_part(2, input, &Parameters::default())
+                .unwrap_err(),
+            SolveError::invalid("the box around the droplet holds more than 1000000 cubes")
+        );
+    }
+}
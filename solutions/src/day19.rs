@@ -1,11 +1,16 @@
-use std::{collections::HashSet, num::ParseIntError};
+use std::collections::HashSet;
 
 use regex::Regex;
 
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
 type Robots = (usize, usize, usize, usize);
 type Storage = (usize, usize, usize, usize);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Blueprint {
     ore: usize,
     clay: usize,
@@ -14,21 +19,19 @@ struct Blueprint {
     max_ore_requirements: usize,
 }
 
-impl std::str::FromStr for Blueprint {
-    type Err = ParseIntError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let re = Regex::new(r"(\d+)").unwrap();
-        let mut data = re.captures_iter(s).skip(1);
-        let ore: usize = data.next().unwrap().get(0).unwrap().as_str().parse()?;
-        let clay: usize = data.next().unwrap().get(0).unwrap().as_str().parse()?;
+impl Blueprint {
+    fn parse(line: &Line, re: &Regex) -> Result<Self, SolveError> {
+        let mut data = re.find_iter(line.text).map(|x| x.as_str());
+        line.parse_next::<usize>(&mut data, "a blueprint id")?;
+        let ore: usize = line.parse_next(&mut data, "an ore robot cost")?;
+        let clay: usize = line.parse_next(&mut data, "a clay robot cost")?;
         let obsidian: (usize, usize) = (
-            data.next().unwrap().get(0).unwrap().as_str().parse()?,
-            data.next().unwrap().get(0).unwrap().as_str().parse()?,
+            line.parse_next(&mut data, "an obsidian robot ore cost")?,
+            line.parse_next(&mut data, "an obsidian robot clay cost")?,
         );
         let geode: (usize, usize) = (
-            data.next().unwrap().get(0).unwrap().as_str().parse()?,
-            data.next().unwrap().get(0).unwrap().as_str().parse()?,
+            line.parse_next(&mut data, "a geode robot ore cost")?,
+            line.parse_next(&mut data, "a geode robot obsidian cost")?,
         );
         let max_ore_requirements = ore.max(clay.max(obsidian.0.max(geode.0)));
         Ok(Self {
@@ -41,19 +44,37 @@ impl std::str::FromStr for Blueprint {
     }
 }
 
-pub fn solve(input: &str) -> (String, String) {
-    let part1 = part1(parse_input(input));
-    let part2 = part2(parse_input(input));
+pub struct Day19;
+
+impl Solver for Day19 {
+    fn day(&self) -> usize {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Not Enough Minerals"
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Blueprint> {
+fn parse_input(input: &str) -> Result<Vec<Blueprint>, SolveError> {
     let mut blueprints = vec![];
-    for line in input.lines() {
-        blueprints.push(line.parse().unwrap());
+    let re = Regex::new(r"(\d+)").unwrap();
+    for line in lines(input) {
+        blueprints.push(Blueprint::parse(&line, &re)?);
     }
-    blueprints
+    Ok(blueprints)
 }
 
 fn get_max_geode(
@@ -244,7 +265,7 @@ mod tests {
             Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
             Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
         "};
-        let (part1, part2) = solve(input);
+        let (part1, part2) = Day19.solve(input, &Parameters::default()).unwrap();
 
         assert_eq!(&part1, "33");
         assert_eq!(&part2, "3472");
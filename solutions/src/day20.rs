@@ -0,0 +1,136 @@
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const POSITIONS: [usize; 3] = [1000, 2000, 3000];
+const DECRYPTION_KEY: i64 = 811589153;
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    fn day(&self) -> usize {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Grove Positioning System"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(usize, i64)>, SolveError> {
+    let file: Vec<(usize, i64)> = lines(input)
+        .enumerate()
+        .map(|(idx, line)| Ok((idx, line.parse(line.text)?)))
+        .collect::<Result<_, SolveError>>()?;
+    if file.len() < 2 {
+        return Err(SolveError::invalid("expected at least 2 numbers"));
+    }
+    if !file.iter().any(|x| x.1 == 0) {
+        return Err(SolveError::invalid("expected a 0 in the file"));
+    }
+    Ok(file)
+}
+
+fn do_mixing(file: &mut Vec<(usize, i64)>) {
+    let mut idx = 0;
+    while idx < file.len() {
+        let acutal_idx = file
+            .iter()
+            .enumerate()
+            .find(|(_, x)| x.0 == idx)
+            .map(|x| x.0)
+            .unwrap() as i64;
+        let el = file.remove(acutal_idx as usize);
+        let len = file.len() as i64;
+        file.insert(
+            (el.1.rem_euclid(len) + acutal_idx).rem_euclid(len) as usize,
+            el,
+        );
+        idx += 1;
+    }
+}
+
+fn get_coordinate_sum(file: &[(usize, i64)]) -> Result<i64, SolveError> {
+    let start_idx = file
+        .iter()
+        .enumerate()
+        .find(|(_, x)| x.1 == 0)
+        .map(|x| x.0)
+        .unwrap();
+    POSITIONS
+        .iter()
+        .map(|i| file.get((start_idx + i) % file.len()).unwrap().1)
+        .try_fold(0i64, |sum, value| sum.checked_add(value))
+        .ok_or_else(|| SolveError::invalid("the grove coordinates do not fit in 64 bits"))
+}
+
+fn part1(mut file: Vec<(usize, i64)>) -> Result<i64, SolveError> {
+    do_mixing(&mut file);
+    get_coordinate_sum(&file)
+}
+
+fn part2(mut file: Vec<(usize, i64)>) -> Result<i64, SolveError> {
+    for (_, value) in file.iter_mut() {
+        *value = value.checked_mul(DECRYPTION_KEY).ok_or_else(|| {
+            SolveError::invalid("the numbers times the decryption key do not fit in 64 bits")
+        })?;
+    }
+    for _ in 0..10 {
+        do_mixing(&mut file);
+    }
+    get_coordinate_sum(&file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            1
+            2
+            -3
+            3
+            -2
+            0
+            4
+        "};
+        let (part1, part2) = Day20.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "3");
+        assert_eq!(&part2, "1623178306");
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        // Moving, summing and applying the key overflowed.
+        let input = "9223372036854775807\n0\n9223372036854775807\n";
+        assert_eq!(
+            Day20
+                .solve_part(1, input, &Parameters::default())
+                .unwrap_err(),
+            SolveError::invalid("the grove coordinates do not fit in 64 bits")
+        );
+        assert_eq!(
+            Day20
+                .solve_part(2, input, &Parameters::default())
+                .unwrap_err(),
+            SolveError::invalid("the numbers times the decryption key do not fit in 64 bits")
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::{lines, Line, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const ROOT_MONKEY: &str = "root";
+const HUMN_MONKEY: &str = "humn";
+
+enum Location {
+    Left,
+    Right,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, Hash)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    fn parse(line: &Line, s: &str) -> Result<Self, SolveError> {
+        match s {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Sub),
+            "*" => Ok(Self::Mul),
+            "/" => Ok(Self::Div),
+            _ => Err(line.expected(Some(s), "an operator")),
+        }
+    }
+
+    fn execute(&self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            Self::Add => lhs.checked_add(rhs),
+            Self::Sub => lhs.checked_sub(rhs),
+            Self::Mul => lhs.checked_mul(rhs),
+            Self::Div => lhs.checked_div(rhs),
+        }
+    }
+
+    fn rev_ex(&self, lhs: i64, rhs: i64, switched: bool) -> Option<i64> {
+        match self {
+            Self::Add => lhs.checked_sub(rhs),
+            Self::Sub => {
+                if switched {
+                    return rhs.checked_sub(lhs);
+                }
+                lhs.checked_add(rhs)
+            }
+            Self::Mul => lhs.checked_div(rhs),
+            Self::Div => {
+                if switched {
+                    return rhs.checked_div(lhs);
+                }
+                lhs.checked_mul(rhs)
+            }
+        }
+    }
+}
+
+#[derive(Hash, Clone)]
+enum Monkey {
+    Value(i64),
+    Operation(String, String, Operator),
+}
+
+impl Monkey {
+    fn parse(line: &Line, s: &str) -> Result<Self, SolveError> {
+        if let Ok(value) = s.parse() {
+            return Ok(Monkey::Value(value));
+        }
+        let mut op_parts = s.split(' ');
+        let lhs = line.next(&mut op_parts, "a monkey name")?;
+        let operator = Operator::parse(line, line.next(&mut op_parts, "an operator")?)?;
+        let rhs = line.next(&mut op_parts, "a monkey name")?;
+        Ok(Self::Operation(lhs.to_owned(), rhs.to_owned(), operator))
+    }
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    fn day(&self) -> usize {
+        21
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey Math"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, Monkey>, SolveError> {
+    let mut monkeys = HashMap::new();
+    for line in lines(input) {
+        let mut parts = line.text.split(": ");
+        let key = line.next(&mut parts, "a monkey name")?;
+        let monkey = Monkey::parse(&line, line.next(&mut parts, "a job")?)?;
+        monkeys.insert(key.to_owned(), monkey);
+    }
+    validate(ROOT_MONKEY, &monkeys, 0, &mut HashSet::new())?;
+    Ok(monkeys)
+}
+
+fn validate<'a>(
+    current: &'a str,
+    monkeys: &'a HashMap<String, Monkey>,
+    depth: usize,
+    done: &mut HashSet<&'a str>,
+) -> Result<(), SolveError> {
+    if done.contains(current) {
+        return Ok(());
+    }
+    if depth > monkeys.len() {
+        return Err(SolveError::invalid(format!(
+            "monkey {:?} depends on itself",
+            current
+        )));
+    }
+    match monkeys.get(current) {
+        Some(Monkey::Value(_)) => {}
+        Some(Monkey::Operation(lhs, rhs, _)) => {
+            validate(lhs, monkeys, depth + 1, done)?;
+            validate(rhs, monkeys, depth + 1, done)?;
+        }
+        None => return Err(SolveError::invalid(format!("unknown monkey {:?}", current))),
+    }
+    done.insert(current);
+    Ok(())
+}
+
+fn find_result(current: &str, monkeys: &HashMap<String, Monkey>) -> Result<i64, SolveError> {
+    match monkeys.get(current).unwrap() {
+        Monkey::Value(value) => Ok(*value),
+        Monkey::Operation(lhs, rhs, operator) => {
+            let lhs = find_result(lhs, monkeys)?;
+            let rhs = find_result(rhs, monkeys)?;
+            operator
+                .execute(lhs, rhs)
+                .ok_or_else(|| arithmetic_error(current))
+        }
+    }
+}
+
+fn arithmetic_error(current: &str) -> SolveError {
+    SolveError::invalid(format!("monkey {:?} cannot compute its number", current))
+}
+
+fn calc_humn(
+    current: &str,
+    monkeys: &HashMap<String, Monkey>,
+    value: i64,
+) -> Result<i64, SolveError> {
+    if current == HUMN_MONKEY {
+        return Ok(value);
+    }
+    if let Some(Monkey::Operation(lhs, rhs, operator)) = monkeys.get(current) {
+        let (next, operand, switched) = match find_humn(current, monkeys) {
+            Location::Left => (lhs, find_result(rhs, monkeys)?, false),
+            Location::Right => (rhs, find_result(lhs, monkeys)?, true),
+            Location::None => return Err(humn_error()),
+        };
+        let value = operator
+            .rev_ex(value, operand, switched)
+            .ok_or_else(|| arithmetic_error(current))?;
+        return calc_humn(next, monkeys, value);
+    }
+    Err(humn_error())
+}
+
+fn humn_error() -> SolveError {
+    SolveError::invalid(format!(
+        "monkey {:?} is not an operand of {:?}",
+        HUMN_MONKEY, ROOT_MONKEY
+    ))
+}
+
+fn find_humn(current: &str, monkeys: &HashMap<String, Monkey>) -> Location {
+    if let Some(Monkey::Operation(lhs, rhs, _)) = monkeys.get(current) {
+        if contains_humn(lhs, monkeys) {
+            return Location::Left;
+        }
+        if contains_humn(rhs, monkeys) {
+            return Location::Right;
+        }
+    }
+    Location::None
+}
+
+fn contains_humn(current: &str, monkeys: &HashMap<String, Monkey>) -> bool {
+    if current == HUMN_MONKEY {
+        return true;
+    }
+    if let Some(Monkey::Operation(lhs, rhs, _)) = monkeys.get(current) {
+        return contains_humn(lhs, monkeys) || contains_humn(rhs, monkeys);
+    }
+    false
+}
+
+fn part1(monkeys: HashMap<String, Monkey>) -> Result<i64, SolveError> {
+    find_result(ROOT_MONKEY, &monkeys)
+}
+
+fn part2(monkeys: HashMap<String, Monkey>) -> Result<i64, SolveError> {
+    if let Some(Monkey::Operation(lhs, rhs, _)) = monkeys.get(ROOT_MONKEY) {
+        let (initial_monkey, value) = match find_humn(ROOT_MONKEY, &monkeys) {
+            Location::Left => (lhs, find_result(rhs, &monkeys)?),
+            Location::Right => (rhs, find_result(lhs, &monkeys)?),
+            Location::None => return Err(humn_error()),
+        };
+        return calc_humn(initial_monkey, &monkeys, value);
+    }
+    Err(SolveError::invalid(format!(
+        "monkey {:?} has no operation",
+        ROOT_MONKEY
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use proptest::prelude::*;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            root: pppw + sjmn
+            dbpl: 5
+            cczh: sllz + lgvd
+            zczc: 2
+            ptdq: humn - dvpt
+            dvpt: 3
+            lfqf: 4
+            humn: 5
+            ljgn: 2
+            sjmn: drzm * dbpl
+            sllz: 4
+            pppw: cczh / lfqf
+            lgvd: ljgn * ptdq
+            drzm: hmdt - zczc
+            hmdt: 32
+        "};
+        let (part1, part2) = Day21.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "152");
+        assert_eq!(&part2, "301");
+    }
+
+    fn operator() -> impl Strategy<Value = Operator> {
+        prop_oneof![
+            Just(Operator::Add),
+            Just(Operator::Sub),
+            Just(Operator::Mul),
+            Just(Operator::Div),
+        ]
+    }
+
+    // `x` is the unknown operand, on the right of `operand` when switched.
+    fn execute(operator: Operator, x: i64, operand: i64, switched: bool) -> Option<i64> {
+        if switched {
+            operator.execute(operand, x)
+        } else {
+            operator.execute(x, operand)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn rev_ex_inverts_execute(
+            operator in operator(),
+            x in -1_000_000_000i64..1_000_000_000,
+            operand in -1_000_000_000i64..1_000_000_000,
+            switched: bool,
+        ) {
+            // Integer division only inverts when it is exact, see below.
+            prop_assume!(!matches!(operator, Operator::Div));
+            prop_assume!(operand != 0 || !matches!(operator, Operator::Mul));
+            let value = execute(operator, x, operand, switched).unwrap();
+            prop_assert_eq!(operator.rev_ex(value, operand, switched), Some(x));
+        }
+
+        #[test]
+        fn rev_ex_inverts_exact_division(
+            a in (1i64..1_000_000).prop_union(-1_000_000i64..0),
+            b in (1i64..1_000_000).prop_union(-1_000_000i64..0),
+            switched: bool,
+        ) {
+            let (x, operand) = if switched { (a, a * b) } else { (a * b, a) };
+            let value = execute(Operator::Div, x, operand, switched).unwrap();
+            prop_assert_eq!(Operator::Div.rev_ex(value, operand, switched), Some(x));
+        }
+    }
+}
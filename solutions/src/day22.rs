@@ -5,8 +5,16 @@ use std::{
     str::FromStr,
 };
 
-#[derive(Debug)]
-struct AoCParsingError;
+use crate::{
+    error::SolveError,
+    solver::{unpack, Parameter, Parameters, Parsed, Solver},
+};
+
+const SIZE: Parameter = Parameter {
+    name: "size",
+    description: "Edge length of a cube face",
+    default: 50,
+};
 
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy)]
@@ -16,13 +24,13 @@ enum Rotation {
 }
 
 impl FromStr for Rotation {
-    type Err = AoCParsingError;
+    type Err = SolveError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "R" => Ok(Self::CW),
             "L" => Ok(Self::CCW),
-            _ => Err(AoCParsingError),
+            _ => Err(SolveError::invalid(format!("invalid rotation {:?}", s))),
         }
     }
 }
@@ -33,7 +41,7 @@ enum Instruction {
     Rotation(Rotation),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Playground {
     map: HashMap<Position, Tile>,
     inst: Vec<Instruction>,
@@ -68,7 +76,7 @@ impl Playground {
                 Direction::Left => new_pos.x -= 1,
                 Direction::Up => new_pos.y -= 1,
             }
-            if self.map.get(&new_pos).is_none() {
+            if !self.map.contains_key(&new_pos) {
                 match self.player.dir {
                     Direction::Right => new_pos.x = self.get_min_x_for_y(new_pos.y),
                     Direction::Down => new_pos.y = self.get_min_y_for_x(new_pos.x),
@@ -101,7 +109,7 @@ impl Playground {
                 Direction::Left => new_pos.x -= 1,
                 Direction::Up => new_pos.y -= 1,
             }
-            if self.map.get(&new_pos).is_none() {
+            if !self.map.contains_key(&new_pos) {
                 let (side, rot) = cube
                     .get(&(
                         (self.player.pos.x - 1) / size + 1,
@@ -211,11 +219,13 @@ impl Playground {
 }
 
 impl FromStr for Playground {
-    type Err = AoCParsingError;
+    type Err = SolveError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (map_data, inst) = s.rsplit_once("\n\n").unwrap_or(("", ""));
-        let re_inst = Regex::new(r"(\d+|[LR])").or(Err(AoCParsingError))?;
+        let (map_data, inst) = s.rsplit_once("\n\n").ok_or_else(|| {
+            SolveError::invalid("expected a map and a path separated by a blank line")
+        })?;
+        let re_inst = Regex::new(r"(\d+|[LR])").unwrap();
         let inst: Vec<Instruction> = re_inst
             .find_iter(inst)
             .map(|x| x.as_str().parse())
@@ -233,7 +243,7 @@ impl FromStr for Playground {
                     '.' => map.insert(Position::new(x, y), Tile::Open),
                     '#' => map.insert(Position::new(x, y), Tile::Wall),
                     ' ' => None,
-                    _ => return Err(AoCParsingError),
+                    _ => return Err(SolveError::at(y, x, format!("invalid tile {:?}", el))),
                 };
                 if el != ' ' && y < min_y {
                     min_y = y;
@@ -241,6 +251,10 @@ impl FromStr for Playground {
             }
         }
 
+        if map.is_empty() {
+            return Err(SolveError::invalid("expected at least one tile on the map"));
+        }
+
         for pos in map.keys() {
             if pos.y == min_y && pos.x < min_x {
                 min_x = pos.x;
@@ -255,13 +269,13 @@ impl FromStr for Playground {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Player {
     pos: Position,
     dir: Direction,
 }
 
-#[derive(Debug, FromPrimitive, Clone, Copy)]
+#[derive(Debug, FromPrimitive, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     Right = 0,
     Down = 1,
@@ -288,9 +302,18 @@ impl Direction {
         }
         *self
     }
+
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+        }
+    }
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 struct Position {
     x: usize,
     y: usize,
@@ -302,14 +325,14 @@ impl Position {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Tile {
     Wall,
     Open,
 }
 
 impl FromStr for Instruction {
-    type Err = AoCParsingError;
+    type Err = SolveError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Ok(s) = s.parse() {
@@ -319,15 +342,43 @@ impl FromStr for Instruction {
     }
 }
 
-pub fn solve(input: &str, size: usize) -> (String, String) {
-    let part1 = part1(parse_input(input));
-    let part2 = part2(parse_input(input), input, size);
+pub struct Day22;
+
+impl Solver for Day22 {
+    fn day(&self) -> usize {
+        22
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey Map"
+    }
+
+    fn params(&self) -> &'static [Parameter] {
+        &[SIZE]
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, parsed: &Parsed, params: &Parameters) -> Result<String, SolveError> {
+        let size = params.given(&SIZE).map(|size| size as usize);
+        Ok(part2(unpack(parsed)?, size)?.to_string())
+    }
 }
 
-fn parse_input(input: &str) -> Playground {
-    input.parse().unwrap()
+fn parse_input(input: &str) -> Result<Playground, SolveError> {
+    input.parse()
+}
+
+// A cube unfolds into 6 square faces, so the tile count is 6 times a square.
+fn detect_size(tiles: usize) -> Option<usize> {
+    let size = ((tiles / 6) as f64).sqrt().round() as usize;
+    (size > 0 && 6 * size * size == tiles).then_some(size)
 }
 
 fn part1(mut playground: Playground) -> usize {
@@ -335,29 +386,22 @@ fn part1(mut playground: Playground) -> usize {
     1000 * playground.player.pos.y + 4 * playground.player.pos.x + playground.player.dir as usize
 }
 
-fn part2(mut playground: Playground, s: &str, size: usize) -> usize {
-    let (map_data, _) = s.rsplit_once("\n\n").unwrap_or(("", ""));
-    let mut max_y = 0;
-    let mut max_x = 0;
-
-    let mut map = HashMap::new();
-
-    for (y, row) in map_data.lines().enumerate() {
-        for (x, el) in row.chars().enumerate() {
-            let x = x + 1;
-            let y = y + 1;
-            match el {
-                '.' => map.insert(Position::new(x, y), Tile::Open),
-                '#' => map.insert(Position::new(x, y), Tile::Wall),
-                _ => None,
-            };
-            if x > max_x {
-                max_x = x;
-            }
-            if y > max_y {
-                max_y = y;
-            }
-        }
+fn part2(mut playground: Playground, size: Option<usize>) -> Result<usize, SolveError> {
+    let map = &playground.map;
+    let max_x = map.keys().map(|pos| pos.x).max().unwrap_or(0);
+    let max_y = map.keys().map(|pos| pos.y).max().unwrap_or(0);
+
+    let size = match size {
+        Some(size) => size,
+        None => detect_size(map.len()).ok_or_else(|| {
+            SolveError::invalid(format!(
+                "cannot detect the cube face size from {} tiles, set it explicitly",
+                map.len()
+            ))
+        })?,
+    };
+    if size == 0 {
+        return Err(SolveError::invalid("cube face size must be positive"));
     }
 
     let mut faces: HashSet<(usize, usize)> = HashSet::new();
@@ -370,6 +414,21 @@ fn part2(mut playground: Playground, s: &str, size: usize) -> usize {
         }
     }
 
+    let cube_error = || {
+        SolveError::invalid(format!(
+            "map does not fold into a cube with faces of size {}",
+            size
+        ))
+    };
+    if faces.len() != 6
+        || map.len() != 6 * size * size
+        || !map
+            .keys()
+            .all(|pos| faces.contains(&((pos.x - 1) / size + 1, (pos.y - 1) / size + 1)))
+    {
+        return Err(cube_error());
+    }
+
     let mut cube: HashMap<(usize, usize), (Side, usize)> = HashMap::new();
 
     fold_cube(
@@ -380,9 +439,16 @@ fn part2(mut playground: Playground, s: &str, size: usize) -> usize {
         *faces.iter().next().unwrap(),
     );
 
+    let sides: HashSet<usize> = cube.values().map(|x| x.0 as usize).collect();
+    if sides.len() != 6 {
+        return Err(cube_error());
+    }
+
     playground.execute_new(&cube, size);
 
-    1000 * playground.player.pos.y + 4 * playground.player.pos.x + playground.player.dir as usize
+    Ok(1000 * playground.player.pos.y
+        + 4 * playground.player.pos.x
+        + playground.player.dir as usize)
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -414,12 +480,7 @@ fn fold_cube(
     cube.insert(c_face, (current, rot));
 
     for dir in Direction::DIRECTIONS {
-        let (x, y) = match dir {
-            Direction::Left => (-1, 0),
-            Direction::Right => (1, 0),
-            Direction::Up => (0, -1),
-            Direction::Down => (0, 1),
-        };
+        let (x, y) = dir.offset();
         let new_c_face = (
             (c_face.0 as isize + x) as usize,
             (c_face.1 as isize + y) as usize,
@@ -439,7 +500,35 @@ fn fold_cube(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generate::CUBE_NETS;
     use indoc::indoc;
+    use proptest::prelude::*;
+
+    // Face coordinates of a net as in `part2`, starting at 1.
+    fn net_faces(net: &[&str], turns: usize, mirror: bool) -> Vec<(usize, usize)> {
+        let mut faces: Vec<(isize, isize)> = vec![];
+        for (y, row) in net.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if c == '#' {
+                    faces.push((x as isize, y as isize));
+                }
+            }
+        }
+        for face in &mut faces {
+            if mirror {
+                face.0 = -face.0;
+            }
+            for _ in 0..turns {
+                *face = (-face.1, face.0);
+            }
+        }
+        let min_x = faces.iter().map(|face| face.0).min().unwrap();
+        let min_y = faces.iter().map(|face| face.1).min().unwrap();
+        faces
+            .iter()
+            .map(|(x, y)| ((x - min_x + 1) as usize, (y - min_y + 1) as usize))
+            .collect()
+    }
 
     #[test]
     fn sample() {
@@ -459,9 +548,65 @@ mod tests {
 
             10R5L5R10L4R5L5
         "};
-        let (part1, part2) = solve(input, 4);
+        let mut params = Parameters::default();
+        params.set("size", 4);
+        let (part1, part2) = Day22.solve(input, &params).unwrap();
 
         assert_eq!(&part1, "6032");
         assert_eq!(&part2, "5031");
+        assert_eq!(
+            Day22.solve(input, &Parameters::default()).unwrap(),
+            (part1, part2)
+        );
+    }
+
+    #[test]
+    fn size_detection() {
+        assert_eq!(detect_size(6 * 4 * 4), Some(4));
+        assert_eq!(detect_size(6 * 50 * 50), Some(50));
+        assert_eq!(detect_size(6 * 50 * 50 + 6), None);
+    }
+
+    proptest! {
+        #[test]
+        fn four_rotations_are_identity(dir in prop::sample::select(&Direction::DIRECTIONS[..])) {
+            let mut rotated = dir;
+            for _ in 0..4 {
+                rotated.rotate(Rotation::CW);
+            }
+            prop_assert_eq!(rotated, dir);
+            for _ in 0..4 {
+                rotated.rotate(Rotation::CCW);
+            }
+            prop_assert_eq!(rotated, dir);
+            prop_assert_eq!(rotated.rotate(Rotation::CW).rotate(Rotation::CCW), dir);
+        }
+
+        #[test]
+        fn fold_cube_visits_every_side_once(
+            net in prop::sample::select(&CUBE_NETS[..]),
+            turns in 0usize..4,
+            mirror: bool,
+            start in 0usize..6,
+        ) {
+            let faces = net_faces(net, turns, mirror);
+            let mut cube = HashMap::new();
+            fold_cube(Side::Bottom, 0, &mut cube, &faces.iter().copied().collect(), faces[start]);
+
+            let sides: HashSet<usize> = cube.values().map(|(side, _)| *side as usize).collect();
+            prop_assert_eq!(cube.len(), 6);
+            prop_assert_eq!(sides.len(), 6);
+            // Faces next to each other in the net are linked on the cube as well.
+            for (face, (side, rot)) in &cube {
+                for dir in Direction::DIRECTIONS {
+                    let (x, y) = dir.offset();
+                    let next = ((face.0 as isize + x) as usize, (face.1 as isize + y) as usize);
+                    if let Some((other, _)) = cube.get(&next) {
+                        let link = CUBE_SIDE_LINKS[*side as usize][(dir as usize + rot) % 4];
+                        prop_assert_eq!(link, *other);
+                    }
+                }
+            }
+        }
     }
 }
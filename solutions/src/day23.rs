@@ -1,5 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 struct Position {
     x: isize,
@@ -36,26 +41,56 @@ impl Position {
     }
 }
 
-pub fn solve(input: &str) -> (String, String) {
-    let part1 = part1(parse_input(input));
-    let part2 = part2(parse_input(input));
+pub struct Day23;
+
+impl Solver for Day23 {
+    fn day(&self) -> usize {
+        23
+    }
+
+    fn title(&self) -> &'static str {
+        "Unstable Diffusion"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?).to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?).to_string())
+    }
 }
 
-fn parse_input(input: &str) -> HashSet<Position> {
+fn parse_input(input: &str) -> Result<HashSet<Position>, SolveError> {
     let mut map = HashSet::new();
-    for (y, row) in input.lines().enumerate() {
-        for (x, el) in row.chars().enumerate() {
-            if el == '#' {
-                map.insert(Position {
-                    x: x as isize,
-                    y: y as isize,
-                });
+    for (y, line) in lines(input).enumerate() {
+        for (x, el) in line.text.char_indices() {
+            match el {
+                '#' => {
+                    map.insert(Position {
+                        x: x as isize,
+                        y: y as isize,
+                    });
+                }
+                '.' => {}
+                _ => {
+                    return Err(SolveError::at(
+                        line.number,
+                        x + 1,
+                        format!("invalid tile {:?}", el),
+                    ))
+                }
             }
         }
     }
-    map
+    if map.is_empty() {
+        return Err(SolveError::invalid("expected at least one elf"));
+    }
+    Ok(map)
 }
 
 fn simulate_round(map: &mut HashSet<Position>, i: isize) -> bool {
@@ -192,7 +227,7 @@ mod tests {
             ##.#.##
             .#..#..
         "};
-        let (part1, part2) = solve(input);
+        let (part1, part2) = Day23.solve(input, &Parameters::default()).unwrap();
 
         assert_eq!(&part1, "110");
         assert_eq!(&part2, "20");
@@ -1,5 +1,10 @@
 use std::{collections::HashSet, hash::Hash};
 
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
 const DIRECTIONS: [Position; 5] = [
     Position { x: 0, y: 0 },
     Position { x: 1, y: 0 },
@@ -32,7 +37,7 @@ impl Position {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Valley {
     blizzards: HashSet<(Position, Direction)>,
     max_x: isize,
@@ -40,65 +45,114 @@ struct Valley {
 }
 
 impl Valley {
-    fn new(input: &str) -> Self {
+    fn parse(input: &str) -> Result<Self, SolveError> {
+        let rows: Vec<_> = lines(input).collect();
+        if rows.len() < 3 || rows[0].text.len() < 3 {
+            return Err(SolveError::invalid(
+                "expected a valley of at least 3x3 tiles",
+            ));
+        }
+        let max_x = rows[0].text.len() as isize - 2;
+        let max_y = rows.len() as isize - 2;
         let mut blizzards = HashSet::new();
-        for (y, row) in input.lines().enumerate() {
-            for (x, el) in row.chars().enumerate() {
+        for (y, line) in rows.iter().enumerate() {
+            if line.text.len() as isize != max_x + 2 {
+                return Err(line.error(line.text, "valley is not rectangular"));
+            }
+            for (x, el) in line.text.char_indices() {
                 let dir = match el {
                     '>' => Direction::Right,
                     '<' => Direction::Left,
                     '^' => Direction::Up,
                     'v' => Direction::Down,
-                    _ => continue,
+                    '.' | '#' => continue,
+                    _ => {
+                        return Err(SolveError::at(
+                            line.number,
+                            x + 1,
+                            format!("invalid tile {:?}", el),
+                        ))
+                    }
                 };
-                blizzards.insert((Position::new(x as isize - 1, y as isize - 1), dir));
+                let pos = Position::new(x as isize - 1, y as isize - 1);
+                if pos.x < 0 || pos.x >= max_x || pos.y < 0 || pos.y >= max_y {
+                    return Err(SolveError::at(
+                        line.number,
+                        x + 1,
+                        "blizzard outside of the valley",
+                    ));
+                }
+                blizzards.insert((pos, dir));
             }
         }
-        let rows: Vec<Vec<char>> = input.lines().map(|row| row.chars().collect()).collect();
-        let max_x = rows.first().unwrap().len() as isize - 2;
-        let max_y = rows.len() as isize - 2;
-        Self {
+        Ok(Self {
             blizzards,
             max_x,
             max_y,
-        }
+        })
     }
 }
 
-pub fn solve(input: &str) -> (String, String) {
-    let part1 = part1(parse_input(input));
-    let part2 = part2(parse_input(input));
+pub struct Day24;
+
+impl Solver for Day24 {
+    fn day(&self) -> usize {
+        24
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn title(&self) -> &'static str {
+        "Blizzard Basin"
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part1(unpack(parsed)?)?.to_string())
+    }
+
+    fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok(part2(unpack(parsed)?)?.to_string())
+    }
 }
 
-fn parse_input(input: &str) -> Valley {
-    Valley::new(input)
+fn parse_input(input: &str) -> Result<Valley, SolveError> {
+    Valley::parse(input)
 }
 
-fn part1(mut valley: Valley) -> isize {
+fn part1(mut valley: Valley) -> Result<isize, SolveError> {
     let start = Position::new(0, -1);
     let goal = Position::new(valley.max_x - 1, valley.max_y);
     min_time(&mut valley, &start, &goal)
 }
 
-fn part2(mut valley: Valley) -> isize {
+fn part2(mut valley: Valley) -> Result<isize, SolveError> {
     let mut sum = 0;
     let start = Position::new(0, -1);
     let goal = Position::new(valley.max_x - 1, valley.max_y);
-    sum += min_time(&mut valley, &start, &goal);
-    sum += min_time(&mut valley, &goal, &start);
-    sum += min_time(&mut valley, &start, &goal);
-    sum
+    sum += min_time(&mut valley, &start, &goal)?;
+    sum += min_time(&mut valley, &goal, &start)?;
+    sum += min_time(&mut valley, &start, &goal)?;
+    Ok(sum)
 }
 
-fn min_time(valley: &mut Valley, start: &Position, goal: &Position) -> isize {
+fn min_time(valley: &mut Valley, start: &Position, goal: &Position) -> Result<isize, SolveError> {
+    let period = num::integer::lcm(valley.max_x, valley.max_y);
     let mut time = 0;
     let mut player_positions = HashSet::from([*start]);
+    let mut seen = HashSet::from([(*start, 0)]);
     'outer: loop {
+        if player_positions.is_empty() {
+            return Err(SolveError::invalid(format!(
+                "no path from {:?} to {:?}",
+                (start.x, start.y),
+                (goal.x, goal.y)
+            )));
+        }
         for pos in &player_positions {
             if *pos == *goal {
-                break 'outer time;
+                break 'outer Ok(time);
             }
         }
 
@@ -147,7 +201,9 @@ fn min_time(valley: &mut Valley, start: &Position, goal: &Position) -> isize {
                         continue 'inner;
                     }
                 }
-                new_player_positions.insert(new_pos);
+                if seen.insert((new_pos, (time + 1) % period)) {
+                    new_player_positions.insert(new_pos);
+                }
             }
         }
 
@@ -172,7 +228,7 @@ mod tests {
             #<^v^^>#
             ######.#
         "};
-        let (part1, part2) = solve(input);
+        let (part1, part2) = Day24.solve(input, &Parameters::default()).unwrap();
 
         assert_eq!(&part1, "18");
         assert_eq!(&part2, "54");
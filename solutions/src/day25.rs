@@ -0,0 +1,150 @@
+use crate::{
+    error::{lines, SolveError},
+    solver::{unpack, Parameters, Parsed, Solver},
+};
+
+const BASE: i64 = 5;
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    fn day(&self) -> usize {
+        25
+    }
+
+    fn title(&self) -> &'static str {
+        "Full of Hot Air"
+    }
+
+    fn parts(&self) -> usize {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        part1(unpack(parsed)?)
+    }
+
+    fn part2(&self, _: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+        Ok("Merry Christmas".to_owned())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<char>>, SolveError> {
+    let mut fuel_requirements = vec![];
+    for line in lines(input) {
+        if let Some(digit) = line.text.matches(|c| !"210-=".contains(c)).next() {
+            return Err(line.error(digit, format!("invalid SNAFU digit {:?}", digit)));
+        }
+        fuel_requirements.push(line.text.chars().collect());
+    }
+    Ok(fuel_requirements)
+}
+
+fn char_to_digit(c: &char) -> i64 {
+    match c {
+        '2' => 2,
+        '1' => 1,
+        '0' => 0,
+        '-' => -1,
+        '=' => -2,
+        _ => unimplemented!(),
+    }
+}
+
+fn digit_to_char(d: i64) -> char {
+    match d {
+        2 => '2',
+        1 => '1',
+        0 => '0',
+        -1 => '-',
+        -2 => '=',
+        _ => unimplemented!(),
+    }
+}
+
+// Sums in i128 since the last step of a number close to `i64::MIN` overflows
+// i64 before its digit is added.
+fn from_snafu(snafu: &[char]) -> Option<i64> {
+    let value = snafu.iter().try_fold(0i128, |s, c| {
+        s.checked_mul(BASE as i128)?
+            .checked_add(char_to_digit(c) as i128)
+    })?;
+    value.try_into().ok()
+}
+
+// Returns the digits least significant first.
+fn to_snafu(mut decimal: i64) -> Vec<char> {
+    if decimal == 0 {
+        return vec!['0'];
+    }
+    let mut res = vec![];
+    while decimal != 0 {
+        let mut c = decimal.rem_euclid(BASE);
+        decimal = decimal.div_euclid(BASE);
+        if c >= 3 {
+            decimal += 1;
+            c -= BASE;
+        }
+        res.push(digit_to_char(c));
+    }
+    res
+}
+
+fn part1(fuel_requirements: Vec<Vec<char>>) -> Result<String, SolveError> {
+    let overflow = || SolveError::invalid("the fuel requirements do not fit in 64 bits");
+    let mut sum: i64 = 0;
+    for snafu in &fuel_requirements {
+        let value = from_snafu(snafu).ok_or_else(overflow)?;
+        sum = sum.checked_add(value).ok_or_else(overflow)?;
+    }
+    Ok(to_snafu(sum).iter().rev().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use proptest::prelude::*;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            1=-0-2
+            12111
+            2=0=
+            21
+            2=01
+            111
+            20012
+            112
+            1=-1=
+            1-12
+            12
+            1=
+            122
+        "};
+        let (part1, _) = Day25.solve(input, &Parameters::default()).unwrap();
+
+        assert_eq!(&part1, "2=-1=0");
+    }
+
+    proptest! {
+        #[test]
+        fn snafu_round_trip(decimal: i64) {
+            let mut snafu = to_snafu(decimal);
+            snafu.reverse();
+            prop_assert_eq!(from_snafu(&snafu), Some(decimal));
+        }
+
+        #[test]
+        fn snafu_digits_round_trip(snafu in "[12=\\-][012=\\-]{0,25}") {
+            let digits: Vec<char> = snafu.chars().rev().collect();
+            let decimal = from_snafu(&snafu.chars().collect::<Vec<_>>()).unwrap();
+            prop_assert_eq!(to_snafu(decimal), digits);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    Invalid(String),
+}
+
+impl SolveError {
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::Invalid(message.into())
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Self::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    pub fn column(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() {
+            offset + 1
+        } else {
+            1
+        }
+    }
+
+    pub fn error(&self, token: &str, message: impl Into<String>) -> SolveError {
+        SolveError::at(self.number, self.column(token), message)
+    }
+
+    pub fn expected(&self, token: Option<&str>, expected: &str) -> SolveError {
+        match token {
+            Some(token) => self.error(token, format!("expected {}, found {:?}", expected, token)),
+            None => SolveError::at(
+                self.number,
+                self.text.len() + 1,
+                format!("expected {}, found end of line", expected),
+            ),
+        }
+    }
+
+    pub fn parse<T>(&self, token: &str) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        token
+            .parse()
+            .map_err(|err| self.error(token, format!("invalid value {:?}: {}", token, err)))
+    }
+
+    pub fn next(
+        &self,
+        tokens: &mut impl Iterator<Item = &'a str>,
+        expected: &str,
+    ) -> Result<&'a str, SolveError> {
+        tokens.next().ok_or_else(|| self.expected(None, expected))
+    }
+
+    pub fn keyword(
+        &self,
+        tokens: &mut impl Iterator<Item = &'a str>,
+        keyword: &str,
+    ) -> Result<(), SolveError> {
+        match tokens.next() {
+            Some(token) if token == keyword => Ok(()),
+            token => Err(self.expected(token, &format!("{:?}", keyword))),
+        }
+    }
+
+    pub fn parse_next<T>(
+        &self,
+        tokens: &mut impl Iterator<Item = &'a str>,
+        expected: &str,
+    ) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let token = self.next(tokens, expected)?;
+        self.parse(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context() {
+        let line = lines("1 2\n3 x").nth(1).unwrap();
+        let mut tokens = line.text.split(' ');
+
+        assert_eq!(line.parse_next::<usize>(&mut tokens, "a number"), Ok(3));
+        assert_eq!(
+            line.parse_next::<usize>(&mut tokens, "a number")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: invalid value \"x\": invalid digit found in string"
+        );
+        assert_eq!(
+            line.next(&mut tokens, "a number").unwrap_err().to_string(),
+            "line 2, column 4: expected a number, found end of line"
+        );
+    }
+}
@@ -0,0 +1,1118 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::{self, Write},
+    ops::Range,
+};
+
+use crate::{
+    day09::MAX_STEPS,
+    day11::Day11,
+    solver::{Parameters, Solver},
+};
+
+// The 11 nets of a cube, all other nets are rotations or mirror images.
+pub(crate) const CUBE_NETS: [&[&str]; 11] = [
+    &["#...", "####", "#..."],
+    &["#...", "####", ".#.."],
+    &["#...", "####", "..#."],
+    &["#...", "####", "...#"],
+    &[".#..", "####", ".#.."],
+    &[".#..", "####", "..#."],
+    &["##..", ".###", ".#.."],
+    &["##..", ".###", "..#."],
+    &["##..", ".###", "...#"],
+    &["##..", ".##.", "..##"],
+    &["###..", "..###"],
+];
+
+// SplitMix64, small enough to keep here and stable across platforms, so a seed
+// always gives the same input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        let len = range.end.abs_diff(range.start);
+        range.start.wrapping_add((self.next_u64() % len) as i64)
+    }
+
+    pub fn below(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx + 1));
+        }
+    }
+}
+
+// A random input for the day, shaped like the puzzle inputs and solvable by
+// its solver. Scale 1 is about the size of a real input, larger scales give
+// longer lists and larger grids. Days 16 and 19 grow more slowly, since their
+// solvers are exponential in the number of valves and minutes.
+pub fn generate(day: usize, seed: u64, scale: usize) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    let scale = scale.max(1);
+    let input = match day {
+        1 => day01(rng, scale),
+        2 => day02(rng, scale),
+        3 => day03(rng, scale),
+        4 => day04(rng, scale),
+        5 => day05(rng, scale),
+        6 => day06(rng, scale),
+        7 => day07(rng, scale),
+        8 => day08(rng, scale),
+        9 => day09(rng, scale),
+        10 => day10(rng, scale),
+        11 => until_solved(rng, &Day11, |rng| day11(rng, scale)),
+        12 => day12(rng, scale),
+        13 => day13(rng, scale),
+        14 => day14(rng, scale),
+        15 => day15(rng, scale),
+        16 => day16(rng, scale),
+        17 => day17(rng, scale),
+        18 => day18(rng, scale),
+        19 => day19(rng, scale),
+        20 => day20(rng, scale),
+        21 => day21(rng, scale),
+        22 => day22(rng, scale),
+        23 => day23(rng, scale),
+        24 => day24(rng, scale),
+        25 => day25(rng, scale),
+        _ => return None,
+    };
+    Some(input)
+}
+
+// Draws inputs until the solver answers both parts, for days where some random
+// choices make an input unsolvable, like a monkey squaring the same item too
+// often.
+fn until_solved(rng: &mut Rng, solver: &dyn Solver, draw: impl Fn(&mut Rng) -> String) -> String {
+    loop {
+        let input = draw(rng);
+        if solver.solve(&input, &Parameters::default()).is_ok() {
+            return input;
+        }
+    }
+}
+
+fn letters(range: Range<u8>) -> Vec<char> {
+    range.map(char::from).collect()
+}
+
+fn lowercase_name(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| rng.range(0..26) as u8 + b'a')
+        .map(char::from)
+        .collect()
+}
+
+fn day01(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for elf in 0..250 * scale {
+        if elf > 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.range(1..16) {
+            writeln!(input, "{}", rng.range(1000..70000)).unwrap();
+        }
+    }
+    input
+}
+
+fn day02(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..2500 * scale {
+        let opponent = rng.pick(&['A', 'B', 'C']);
+        let response = rng.pick(&['X', 'Y', 'Z']);
+        writeln!(input, "{} {}", opponent, response).unwrap();
+    }
+    input
+}
+
+// Each rucksack of a group gets its own letters besides the badge, so the badge
+// is the only item they share. A rucksack splits its letters between the
+// compartments, except for the one item in both.
+fn day03(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..100 * scale {
+        let mut items: Vec<char> = letters(b'a'..b'z' + 1)
+            .into_iter()
+            .chain(letters(b'A'..b'Z' + 1))
+            .collect();
+        rng.shuffle(&mut items);
+        let badge = items.pop().unwrap();
+        for own in items.chunks(items.len() / 3) {
+            let (shared, own) = own.split_last().unwrap();
+            let shared = if rng.chance(0.2) { badge } else { *shared };
+            let (left, right) = own.split_at(own.len() / 2);
+            let len = rng.range(8..17) as usize;
+            let mut first = vec![shared];
+            let mut second = vec![shared];
+            if shared != badge {
+                if rng.chance(0.5) {
+                    first.push(badge);
+                } else {
+                    second.push(badge);
+                }
+            }
+            while first.len() < len {
+                first.push(rng.pick(left));
+            }
+            while second.len() < len {
+                second.push(rng.pick(right));
+            }
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+            input.extend(first);
+            input.extend(second);
+            input.push('\n');
+        }
+    }
+    input
+}
+
+fn day04(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..1000 * scale {
+        let mut sections = [0; 4].map(|_| rng.range(1..100));
+        sections[..2].sort();
+        sections[2..].sort();
+        let [a, b, c, d] = sections;
+        writeln!(input, "{}-{},{}-{}", a, b, c, d).unwrap();
+    }
+    input
+}
+
+// Moves never take the last crate off a stack, so no stack ends up empty.
+fn day05(rng: &mut Rng, scale: usize) -> String {
+    let crates = letters(b'A'..b'Z' + 1);
+    let mut stacks: Vec<Vec<char>> = vec![];
+    for _ in 0..9 {
+        let mut stack = vec![];
+        for _ in 0..rng.range(2..9) {
+            stack.push(rng.pick(&crates));
+        }
+        stacks.push(stack);
+    }
+
+    let mut input = String::new();
+    let top = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..top).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_owned(),
+            })
+            .collect();
+        writeln!(input, "{}", row.join(" ")).unwrap();
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect();
+    writeln!(input, "{}\n", numbers.join(" ")).unwrap();
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for _ in 0..500 * scale {
+        let from = loop {
+            let from = rng.below(heights.len());
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = loop {
+            let to = rng.below(heights.len());
+            if to != from {
+                break to;
+            }
+        };
+        let count = rng.range(1..heights[from] as i64) as usize;
+        heights[from] -= count;
+        heights[to] += count;
+        writeln!(input, "move {} from {} to {}", count, from + 1, to + 1).unwrap();
+    }
+    input
+}
+
+// 3 letters can't hold a start-of-packet marker and 13 no start-of-message
+// marker, so the markers come late in the stream.
+fn day06(rng: &mut Rng, scale: usize) -> String {
+    let mut letters = letters(b'a'..b'z' + 1);
+    rng.shuffle(&mut letters);
+    let mut input = String::new();
+    for _ in 0..1000 * scale {
+        input.push(rng.pick(&letters[..3]));
+    }
+    for _ in 0..2000 * scale {
+        input.push(rng.pick(&letters[..13]));
+    }
+    rng.shuffle(&mut letters);
+    input.extend(&letters[..14]);
+    for _ in 0..1000 * scale {
+        input.push(rng.pick(&letters));
+    }
+    input.push('\n');
+    input
+}
+
+struct Directory {
+    name: String,
+    files: Vec<(String, u64)>,
+    dirs: Vec<Directory>,
+}
+
+impl Directory {
+    fn new(rng: &mut Rng, name: String, depth: usize, budget: &mut usize) -> Self {
+        let mut names = HashSet::new();
+        let mut unique_name = |rng: &mut Rng| loop {
+            let len = rng.range(1..9) as usize;
+            let name = lowercase_name(rng, len);
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+        let mut files = vec![];
+        for _ in 0..rng.range(0..5) {
+            let mut name = unique_name(rng);
+            if rng.chance(0.5) {
+                write!(name, ".{}", lowercase_name(rng, 3)).unwrap();
+            }
+            files.push((name, rng.range(1000..300_000) as u64));
+        }
+        let mut dirs = vec![];
+        if depth < 8 {
+            for _ in 0..rng.range(0..4) {
+                if *budget == 0 {
+                    break;
+                }
+                *budget -= 1;
+                let name = unique_name(rng);
+                dirs.push(Self::new(rng, name, depth + 1, budget));
+            }
+        }
+        Self { name, files, dirs }
+    }
+
+    fn weight(&self) -> u64 {
+        let files: u64 = self.files.iter().map(|(_, size)| size).sum();
+        files + self.dirs.iter().map(Directory::weight).sum::<u64>()
+    }
+
+    fn write(&self, input: &mut String, total: u64, weight: u64) {
+        writeln!(input, "$ cd {}", self.name).unwrap();
+        writeln!(input, "$ ls").unwrap();
+        let mut entries: Vec<(&str, String)> = self
+            .dirs
+            .iter()
+            .map(|dir| (dir.name.as_str(), format!("dir {}", dir.name)))
+            .chain(self.files.iter().map(|(name, size)| {
+                let size = (size * total / weight).max(1);
+                (name.as_str(), format!("{} {}", size, name))
+            }))
+            .collect();
+        entries.sort();
+        for (_, entry) in entries {
+            writeln!(input, "{}", entry).unwrap();
+        }
+        for dir in &self.dirs {
+            dir.write(input, total, weight);
+            writeln!(input, "$ cd ..").unwrap();
+        }
+    }
+}
+
+// File sizes are scaled so the disk is between 60% and 97% full.
+fn day07(rng: &mut Rng, scale: usize) -> String {
+    let mut budget = 180 * scale;
+    let mut root = Directory::new(rng, "/".to_owned(), 0, &mut budget);
+    while budget > 0 {
+        budget -= 1;
+        let mut names: HashSet<&str> = root.dirs.iter().map(|dir| dir.name.as_str()).collect();
+        names.extend(root.files.iter().map(|(name, _)| name.as_str()));
+        let name = loop {
+            let len = rng.range(1..9) as usize;
+            let name = lowercase_name(rng, len);
+            if !names.contains(name.as_str()) {
+                break name;
+            }
+        };
+        let dir = Directory::new(rng, name, 1, &mut budget);
+        root.dirs.push(dir);
+    }
+    let total = rng.range(42_000_000..68_000_000) as u64;
+    let weight = root.weight().max(1);
+    let mut input = String::new();
+    root.write(&mut input, total, weight);
+    input
+}
+
+fn day08(rng: &mut Rng, scale: usize) -> String {
+    let side = 99 * scale;
+    let mut input = String::new();
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(char::from(b'0' + rng.range(0..10) as u8));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+// Stops growing where the up to 19 steps a line could exceed what day 9
+// accepts.
+fn day09(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..(2000 * scale).min(MAX_STEPS / 19) {
+        let direction = rng.pick(&['U', 'D', 'L', 'R']);
+        writeln!(input, "{} {}", direction, rng.range(1..20)).unwrap();
+    }
+    input
+}
+
+// Keeps the sprite on the screen and runs for exactly 240 cycles per scale, so
+// the CRT draws full rows.
+fn day10(rng: &mut Rng, scale: usize) -> String {
+    let cycles = 240 * scale;
+    let mut input = String::new();
+    let mut cycle = 0;
+    let mut x = 1;
+    while cycle < cycles {
+        let next = (x + rng.range(-15..16)).clamp(-1, 40);
+        if cycles - cycle >= 2 && next != x && rng.chance(0.6) {
+            writeln!(input, "addx {}", next - x).unwrap();
+            x = next;
+            cycle += 2;
+        } else {
+            input.push_str("noop\n");
+            cycle += 1;
+        }
+    }
+    input
+}
+
+fn day11(rng: &mut Rng, scale: usize) -> String {
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let monkeys = 8;
+    let squares = rng.below(monkeys);
+    let mut input = String::new();
+    for (monkey, prime) in primes.iter().take(monkeys).enumerate() {
+        if monkey > 0 {
+            input.push('\n');
+        }
+        let mut items = vec![];
+        for _ in 0..rng.range(1..8 * scale as i64 + 1) {
+            items.push(rng.range(50..100).to_string());
+        }
+        let operation = if monkey == squares {
+            "old * old".to_owned()
+        } else if rng.chance(0.3) {
+            format!("old * {}", rng.range(2..20))
+        } else {
+            format!("old + {}", rng.range(1..9))
+        };
+        let others: Vec<usize> = (0..monkeys).filter(|other| *other != monkey).collect();
+        let positive = rng.pick(&others);
+        let negative = loop {
+            let negative = rng.pick(&others);
+            if negative != positive {
+                break negative;
+            }
+        };
+        writeln!(input, "Monkey {}:", monkey).unwrap();
+        writeln!(input, "  Starting items: {}", items.join(", ")).unwrap();
+        writeln!(input, "  Operation: new = {}", operation).unwrap();
+        writeln!(input, "  Test: divisible by {}", prime).unwrap();
+        writeln!(input, "    If true: throw to monkey {}", positive).unwrap();
+        writeln!(input, "    If false: throw to monkey {}", negative).unwrap();
+    }
+    input
+}
+
+// Hills whose height drops by at most one per step away from their top, so
+// neighbouring squares differ by at most one and every square can be climbed.
+// No hill reaches the left column, where the start is among other `a` squares.
+fn day12(rng: &mut Rng, scale: usize) -> String {
+    let (width, height) = (160 * scale as i64, 41);
+    let hill = |rng: &mut Rng, top: i64| {
+        let slope = rng.range(1..4);
+        (
+            rng.range(top * slope + 1..width),
+            rng.range(0..height),
+            top,
+            slope,
+        )
+    };
+    let mut hills = vec![hill(rng, 25)];
+    for _ in 0..8 * scale {
+        let top = rng.range(5..25);
+        hills.push(hill(rng, top));
+    }
+    let end = (hills[0].0, hills[0].1);
+    let start = (0, rng.range(0..height));
+
+    let mut input = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let square = if (x, y) == start {
+                'S'
+            } else if (x, y) == end {
+                'E'
+            } else {
+                let elevation = hills
+                    .iter()
+                    .map(|(hx, hy, top, slope)| {
+                        let distance = (((x - hx).pow(2) + (y - hy).pow(2)) as f64).sqrt();
+                        top - (distance / *slope as f64) as i64
+                    })
+                    .max()
+                    .unwrap()
+                    .max(0);
+                char::from(b'a' + elevation as u8)
+            };
+            input.push(square);
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[derive(Clone)]
+enum Packet {
+    Integer(i64),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn list(rng: &mut Rng, depth: usize) -> Self {
+        let mut items = vec![];
+        for _ in 0..rng.range(0..5) {
+            items.push(if depth < 4 && rng.chance(0.3) {
+                Self::list(rng, depth + 1)
+            } else {
+                Self::Integer(rng.range(0..11))
+            });
+        }
+        Self::List(items)
+    }
+
+    // Changes one integer or list somewhere, so the pair differs deep inside.
+    fn mutate(&mut self, rng: &mut Rng, depth: usize) {
+        match self {
+            Self::Integer(value) => *value = rng.range(0..11),
+            Self::List(items) if items.is_empty() || rng.chance(0.2) => {
+                if items.is_empty() || rng.chance(0.5) {
+                    items.push(Self::list(rng, depth + 1));
+                } else {
+                    items.pop();
+                }
+            }
+            Self::List(items) => {
+                let idx = rng.below(items.len());
+                items[idx].mutate(rng, depth + 1);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::List(items) => {
+                let items: Vec<String> = items.iter().map(Self::to_string).collect();
+                write!(f, "[{}]", items.join(","))
+            }
+        }
+    }
+}
+
+fn day13(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for pair in 0..150 * scale {
+        if pair > 0 {
+            input.push('\n');
+        }
+        let left = Packet::list(rng, 0);
+        let right = if rng.chance(0.5) {
+            let mut right = left.clone();
+            right.mutate(rng, 0);
+            right
+        } else {
+            Packet::list(rng, 0)
+        };
+        writeln!(input, "{}\n{}", left, right).unwrap();
+    }
+    input
+}
+
+fn day14(rng: &mut Rng, scale: usize) -> String {
+    // Day 14 rejects rocks more than 10000 to either side.
+    let spread = (40 * scale as i64).min(9_000);
+    let mut input = String::new();
+    for _ in 0..40 * scale {
+        let mut x = 500 + rng.range(-spread..spread);
+        let mut y = rng.range(13..170);
+        let mut points = vec![format!("{},{}", x, y)];
+        let mut horizontal = rng.chance(0.5);
+        for _ in 0..rng.range(1..6) {
+            let sign = rng.pick(&[-1, 1]);
+            if horizontal {
+                x += sign * rng.range(2..16);
+            } else {
+                y = (y + sign * rng.range(1..9)).max(13);
+            }
+            horizontal = !horizontal;
+            points.push(format!("{},{}", x, y));
+        }
+        writeln!(input, "{}", points.join(" -> ")).unwrap();
+    }
+    input
+}
+
+// Every sensor's range stops one short of the distress beacon. A sensor in each
+// corner of the search area covers the rectangle between the corner and the
+// distress beacon, so together they leave exactly that one position.
+fn day15(rng: &mut Rng, scale: usize) -> String {
+    const SIZE: i64 = 4_000_000;
+    let gap = (rng.range(1..SIZE), rng.range(1..SIZE));
+    let distance = |(x, y): (i64, i64)| (x - gap.0).abs() + (y - gap.1).abs();
+    let mut sensors: Vec<((i64, i64), i64)> = [(0, 0), (SIZE, 0), (0, SIZE), (SIZE, SIZE)]
+        .into_iter()
+        .map(|corner| (corner, distance(corner) - 1))
+        .collect();
+    while sensors.len() < 20 * scale + 4 {
+        let sensor = (rng.range(0..SIZE + 1), rng.range(0..SIZE + 1));
+        let max = distance(sensor).min(1_500_000);
+        if max >= 2 {
+            sensors.push((sensor, rng.range(max / 2..max)));
+        }
+    }
+    rng.shuffle(&mut sensors);
+
+    let mut input = String::new();
+    for ((x, y), range) in sensors {
+        let dx = rng.range(0..range + 1);
+        let beacon = (
+            x + rng.pick(&[-1, 1]) * dx,
+            y + rng.pick(&[-1, 1]) * (range - dx),
+        );
+        writeln!(
+            input,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            x, y, beacon.0, beacon.1
+        )
+        .unwrap();
+    }
+    input
+}
+
+fn day16(rng: &mut Rng, scale: usize) -> String {
+    let count = 10 * scale + 5;
+    let mut names = BTreeSet::from(["AA".to_owned()]);
+    while names.len() < count {
+        names.insert(
+            (0..2)
+                .map(|_| char::from(rng.range(0..26) as u8 + b'A'))
+                .collect(),
+        );
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    rng.shuffle(&mut names[1..]);
+
+    // A random tree with a few more tunnels, so every valve can be reached.
+    let mut tunnels = vec![BTreeSet::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.below(valve));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.below(count), rng.below(count));
+    }
+    let mut rates = vec![0; count];
+    for rate in rates.iter_mut().skip(1).take((5 + scale).min(count - 1)) {
+        *rate = rng.range(3..26);
+    }
+    rng.shuffle(&mut rates[1..]);
+
+    let mut input = String::new();
+    for (valve, name) in names.iter().enumerate() {
+        let mut next: Vec<&str> = tunnels[valve]
+            .iter()
+            .map(|other| names[*other].as_str())
+            .collect();
+        rng.shuffle(&mut next);
+        let tunnels = if next.len() == 1 {
+            "tunnel leads to valve"
+        } else {
+            "tunnels lead to valves"
+        };
+        writeln!(
+            input,
+            "Valve {} has flow rate={}; {} {}",
+            name,
+            rates[valve],
+            tunnels,
+            next.join(", ")
+        )
+        .unwrap();
+    }
+    input
+}
+
+fn day17(rng: &mut Rng, scale: usize) -> String {
+    let len = 10_000 * scale + rng.below(100);
+    let mut input: String = (0..len).map(|_| rng.pick(&['<', '>'])).collect();
+    input.push('\n');
+    input
+}
+
+// A few overlapping balls of lava with random holes, some of them trapped
+// inside.
+fn day18(rng: &mut Rng, scale: usize) -> String {
+    let side = 20 * scale as i64;
+    let mut balls = vec![];
+    for _ in 0..4 * scale {
+        let radius = rng.range(4..8);
+        let mut center = || rng.range(radius..side - radius);
+        balls.push(([center(), center(), center()], radius));
+    }
+    let mut input = String::new();
+    for x in 0..side {
+        for y in 0..side {
+            for z in 0..side {
+                let inside = balls.iter().any(|([cx, cy, cz], radius)| {
+                    (x - cx).pow(2) + (y - cy).pow(2) + (z - cz).pow(2) <= radius.pow(2)
+                });
+                if inside && rng.chance(0.85) {
+                    writeln!(input, "{},{},{}", x, y, z).unwrap();
+                }
+            }
+        }
+    }
+    input
+}
+
+fn day19(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=30 * scale {
+        writeln!(
+            input,
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+            Each obsidian robot costs {} ore and {} clay. \
+            Each geode robot costs {} ore and {} obsidian.",
+            id,
+            rng.range(2..5),
+            rng.range(2..5),
+            rng.range(2..5),
+            rng.range(3..21),
+            rng.range(2..5),
+            rng.range(5..21),
+        )
+        .unwrap();
+    }
+    input
+}
+
+fn day20(rng: &mut Rng, scale: usize) -> String {
+    let len = 5000 * scale;
+    let zero = rng.below(len);
+    let mut input = String::new();
+    for idx in 0..len {
+        let number = if idx == zero {
+            0
+        } else {
+            loop {
+                let number = rng.range(-10_000..10_001);
+                if number != 0 {
+                    break number;
+                }
+            }
+        };
+        writeln!(input, "{}", number).unwrap();
+    }
+    input
+}
+
+struct MonkeyMath<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl MonkeyMath<'_> {
+    fn monkey(&mut self, job: String) -> String {
+        let name = loop {
+            let name = lowercase_name(self.rng, 4);
+            if self.names.insert(name.clone()) {
+                break name;
+            }
+        };
+        self.jobs.push(format!("{}: {}", name, job));
+        name
+    }
+
+    fn operation(&mut self, lhs: String, operator: char, rhs: String, swap: bool) -> String {
+        if swap {
+            self.monkey(format!("{} {} {}", rhs, operator, lhs))
+        } else {
+            self.monkey(format!("{} {} {}", lhs, operator, rhs))
+        }
+    }
+
+    // A monkey yelling `value`, worked out from small numbers like in the
+    // puzzle. Mostly splits the value by a small factor, so the tree stays
+    // shallow, and sometimes goes through a subtraction or division first.
+    fn number(&mut self, value: i64, split: bool) -> String {
+        if (0..=20).contains(&value) && (value < 4 || self.rng.chance(0.7)) {
+            return self.monkey(value.to_string());
+        }
+        if !split && value <= 1_000_000_000 && self.rng.chance(0.15) {
+            let divisor = self.rng.range(2..6);
+            let lhs = self.number(value * divisor, true);
+            let rhs = self.monkey(divisor.to_string());
+            return self.operation(lhs, '/', rhs, false);
+        }
+        if !split && self.rng.chance(0.15) {
+            let offset = self.rng.range(1..21);
+            let lhs = self.number(value + offset, true);
+            let rhs = self.monkey(offset.to_string());
+            return self.operation(lhs, '-', rhs, false);
+        }
+        let factor = self.rng.range(2..10).min(value.max(2));
+        let product = {
+            let lhs = self.number(value / factor, false);
+            let rhs = self.monkey(factor.to_string());
+            let swap = self.rng.chance(0.5);
+            self.operation(lhs, '*', rhs, swap)
+        };
+        match value % factor {
+            0 => product,
+            rest => {
+                let rest = self.monkey(rest.to_string());
+                let swap = self.rng.chance(0.5);
+                self.operation(product, '+', rest, swap)
+            }
+        }
+    }
+}
+
+// The most a monkey on the way from humn to the root yells, with the answer and
+// with the part 1 number alike. Far from overflowing, even summed at the root.
+const MONKEY_LIMIT: i64 = 1_000_000_000_000_000;
+
+// Works up from the answer to part 2 through a chain of monkeys above `humn`,
+// only dividing when it leaves no remainder, so the chain can be inverted.
+// `root` then compares the top of the chain with a monkey yelling the same
+// number.
+fn day21(rng: &mut Rng, scale: usize) -> String {
+    let answer = rng.range(100_000_000_000..10_000_000_000_000);
+    let humn = rng.range(1..5000);
+    let mut math = MonkeyMath {
+        rng,
+        names: HashSet::from(["root".to_owned(), "humn".to_owned()]),
+        jobs: vec![format!("humn: {}", humn)],
+    };
+    let mut chain = "humn".to_owned();
+    // What the chain yells when humn yells the answer, and when it yells the
+    // number from the input like in part 1.
+    let mut value = answer;
+    let mut part1 = humn;
+    for _ in 0..70 * scale {
+        let divisor = (2..10).find(|divisor| value % divisor == 0);
+        let large = value.max(part1.abs()) > 10_000_000_000_000;
+        let choice = match (large, divisor) {
+            (true, Some(_)) => 4,
+            (true, None) => 1,
+            (false, _) => math.rng.range(0..5),
+        };
+        // `reversed` subtracts the chain from the operand.
+        let (operator, operand, reversed) = match (choice, divisor) {
+            (0, _) => ('+', math.rng.range(1..1_000_000), false),
+            (1, _) if value > 1 => ('-', math.rng.range(1..value.min(1_000_000)), false),
+            (2, _) => ('-', value + math.rng.range(1..1_000_000), true),
+            (3, _) => ('*', math.rng.range(2..10), false),
+            (_, Some(divisor)) => ('/', divisor, false),
+            _ => continue,
+        };
+        let apply = |number: i64| match operator {
+            '+' => number.checked_add(operand),
+            '-' if reversed => operand.checked_sub(number),
+            '-' => number.checked_sub(operand),
+            '*' => number.checked_mul(operand),
+            _ => number.checked_div(operand),
+        };
+        // Anything the chain yells on the way to the root has to fit, so the
+        // step is left out rather than taken past the limit.
+        match (apply(value), apply(part1)) {
+            (Some(next), Some(next_part1))
+                if next.abs() <= MONKEY_LIMIT && next_part1.abs() <= MONKEY_LIMIT =>
+            {
+                value = next;
+                part1 = next_part1;
+            }
+            _ => continue,
+        }
+        let operand = match operator {
+            '*' | '/' => math.monkey(operand.to_string()),
+            _ => math.number(operand, false),
+        };
+        let swap = match operator {
+            '+' | '*' => math.rng.chance(0.5),
+            _ => reversed,
+        };
+        chain = math.operation(chain, operator, operand, swap);
+    }
+    let other = math.number(value, false);
+    let (lhs, rhs) = if math.rng.chance(0.5) {
+        (other, chain)
+    } else {
+        (chain, other)
+    };
+    math.jobs.push(format!("root: {} + {}", lhs, rhs));
+    math.rng.shuffle(&mut math.jobs);
+    math.jobs.iter().map(|job| format!("{}\n", job)).collect()
+}
+
+// Lays out a random cube net, turned and mirrored, with faces of 50 tiles per
+// scale.
+fn day22(rng: &mut Rng, scale: usize) -> String {
+    let size = 50 * scale;
+    let net = rng.pick(&CUBE_NETS);
+    let mirror = rng.chance(0.5);
+    let turns = rng.below(4);
+    let mut faces: Vec<(isize, isize)> = vec![];
+    for (y, row) in net.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            if c == '#' {
+                faces.push((x as isize, y as isize));
+            }
+        }
+    }
+    for face in &mut faces {
+        if mirror {
+            face.0 = -face.0;
+        }
+        for _ in 0..turns {
+            *face = (-face.1, face.0);
+        }
+    }
+    let min_x = faces.iter().map(|face| face.0).min().unwrap();
+    let min_y = faces.iter().map(|face| face.1).min().unwrap();
+    let faces: HashSet<(usize, usize)> = faces
+        .iter()
+        .map(|(x, y)| ((x - min_x) as usize, (y - min_y) as usize))
+        .collect();
+    let width = faces.iter().map(|face| face.0 + 1).max().unwrap() * size;
+    let height = faces.iter().map(|face| face.1 + 1).max().unwrap() * size;
+
+    let mut input = String::new();
+    for y in 0..height {
+        let mut row = String::new();
+        for x in 0..width {
+            row.push(if !faces.contains(&(x / size, y / size)) {
+                ' '
+            } else if rng.chance(0.1) && !(y == 0 && row.trim().is_empty()) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+        writeln!(input, "{}", row.trim_end()).unwrap();
+    }
+    input.push('\n');
+    for step in 0..2000 * scale {
+        if step > 0 {
+            input.push(rng.pick(&['L', 'R']));
+        }
+        write!(input, "{}", rng.range(1..51)).unwrap();
+    }
+    input.push('\n');
+    input
+}
+
+fn day23(rng: &mut Rng, scale: usize) -> String {
+    let side = 72 * scale;
+    let mut input = String::new();
+    for _ in 0..side {
+        for _ in 0..side {
+            input.push(if rng.chance(0.45) { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+// Like the puzzle, no blizzard moves up or down in the columns of the entrance
+// and the exit. Valleys the expedition can't cross are drawn again.
+fn day24(rng: &mut Rng, scale: usize) -> String {
+    let (width, height) = (120 * scale, 25);
+    let valley = loop {
+        let mut valley = vec![vec!['.'; width]; height];
+        for row in valley.iter_mut() {
+            for (x, tile) in row.iter_mut().enumerate() {
+                if !rng.chance(0.6) {
+                    continue;
+                }
+                *tile = if x == 0 || x == width - 1 {
+                    rng.pick(&['<', '>'])
+                } else {
+                    rng.pick(&['<', '>', '^', 'v'])
+                };
+            }
+        }
+        if crosses_valley(&valley) {
+            break valley;
+        }
+    };
+    let mut input = format!("#.{}\n", "#".repeat(width));
+    for row in valley {
+        writeln!(input, "#{}#", row.into_iter().collect::<String>()).unwrap();
+    }
+    writeln!(input, "{}.#", "#".repeat(width)).unwrap();
+    input
+}
+
+// Whether the expedition gets to the exit, back and to the exit again as in
+// part 2. Instead of moving every blizzard each minute like the solver, which
+// takes seconds on a full valley, it looks at the tiles a blizzard would have
+// started from to be on a tile by then.
+fn crosses_valley(valley: &[Vec<char>]) -> bool {
+    let (width, height) = (valley[0].len() as isize, valley.len() as isize);
+    let period = num::integer::lcm(width, height);
+    let tile =
+        |x: isize, y: isize| valley[y.rem_euclid(height) as usize][x.rem_euclid(width) as usize];
+    let free = |(x, y): (isize, isize), time: isize| {
+        !(0..height).contains(&y)
+            || (tile(x - time, y) != '>'
+                && tile(x + time, y) != '<'
+                && tile(x, y - time) != 'v'
+                && tile(x, y + time) != '^')
+    };
+    let (entrance, exit) = ((0, -1), (width - 1, height));
+    let state = |(x, y): (isize, isize), time: isize| {
+        (((y + 1) * width + x) * period + time % period) as usize
+    };
+    let mut seen = vec![false; state((0, height + 1), 0)];
+    let mut time = 0;
+    for (from, to) in [(entrance, exit), (exit, entrance), (entrance, exit)] {
+        seen.fill(false);
+        let mut positions = vec![from];
+        while !positions.contains(&to) {
+            time += 1;
+            let mut next = vec![];
+            for (x, y) in positions {
+                for (dx, dy) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    let pos = (x + dx, y + dy);
+                    let inside = (0..width).contains(&pos.0) && (0..height).contains(&pos.1);
+                    if (inside || pos == entrance || pos == exit)
+                        && free(pos, time)
+                        && !std::mem::replace(&mut seen[state(pos, time)], true)
+                    {
+                        next.push(pos);
+                    }
+                }
+            }
+            if next.is_empty() {
+                return false;
+            }
+            positions = next;
+        }
+    }
+    true
+}
+
+fn day25(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..120 * scale {
+        input.push(rng.pick(&['1', '2']));
+        for _ in 0..rng.range(0..20) {
+            input.push(rng.pick(&['=', '-', '0', '1', '2']));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        day09::Day09,
+        day21::Day21,
+        solver::{Parameters, Registry},
+    };
+
+    #[test]
+    fn inputs() {
+        let registry = Registry::default();
+        for solver in registry.iter() {
+            let input = generate(solver.day(), 7, 1).unwrap();
+            assert_eq!(generate(solver.day(), 7, 1).as_ref(), Some(&input));
+            assert_ne!(generate(solver.day(), 8, 1).as_ref(), Some(&input));
+            let parsed = solver.parse(&input).unwrap();
+            for part in 1..=solver.parts() {
+                let answer = solver.solve_parsed(part, &parsed, &Parameters::default());
+                assert!(
+                    answer.is_ok(),
+                    "day {} part {}: {:?}",
+                    solver.day(),
+                    part,
+                    answer
+                );
+            }
+        }
+        // Large scales stay within the bounds the solvers check.
+        assert!(Day09.parse(&generate(9, 7, 1000).unwrap()).is_ok());
+        assert_eq!(generate(26, 7, 1), None);
+    }
+
+    #[test]
+    fn monkey_math() {
+        // With humn yelling the part 2 answer, both sides of the root match.
+        for seed in 0..200 {
+            let input = generate(21, seed, 1).unwrap();
+            let (_, humn) = Day21.solve(&input, &Parameters::default()).unwrap();
+            let input: String = input
+                .lines()
+                .map(|line| match line.split_once(": ") {
+                    Some(("humn", _)) => format!("humn: {}\n", humn),
+                    Some(("root", job)) => format!("root: {}\n", job.replace('+', "-")),
+                    _ => format!("{}\n", line),
+                })
+                .collect();
+            assert_eq!(
+                Day21.solve_part(1, &input, &Parameters::default()),
+                Ok("0".to_owned()),
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
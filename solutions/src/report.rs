@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use crate::{
+    error::SolveError,
+    solver::{Parameters, Solver},
+    timing::Timed,
+};
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PartReport {
+    pub part: usize,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "elapsed_ns",
+            serialize_with = "crate::timing::serialize_nanos"
+        )
+    )]
+    pub elapsed: Duration,
+}
+
+// Everything about one run of a solver. `params` only holds the parameters
+// that were set, the others use their defaults or are detected from the input.
+// `error` is the parse error or the first error of a part, so it is set
+// whenever the run failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SolveReport {
+    pub day: usize,
+    pub title: &'static str,
+    pub version: &'static str,
+    pub params: Parameters,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "parse_ns",
+            serialize_with = "crate::timing::serialize_optional_nanos"
+        )
+    )]
+    pub parse: Option<Duration>,
+    pub parts: Vec<PartReport>,
+    pub error: Option<String>,
+}
+
+impl SolveReport {
+    pub fn new(
+        solver: &dyn Solver,
+        params: &Parameters,
+        result: &Result<Timed, SolveError>,
+    ) -> Self {
+        let mut report = Self {
+            day: solver.day(),
+            title: solver.title(),
+            version: VERSION,
+            params: params.clone(),
+            parse: None,
+            parts: vec![],
+            error: None,
+        };
+        match result {
+            Ok(timed) => {
+                report.parse = Some(timed.parse);
+                report.parts = (1..)
+                    .zip(&timed.parts)
+                    .map(|(part, (result, elapsed))| PartReport {
+                        part,
+                        answer: result.as_ref().ok().cloned(),
+                        error: result.as_ref().err().map(ToString::to_string),
+                        elapsed: *elapsed,
+                    })
+                    .collect();
+                report.error = timed.error().map(ToString::to_string);
+            }
+            Err(err) => report.error = Some(err.to_string()),
+        }
+        report
+    }
+
+    pub fn run(solver: &dyn Solver, input: &str, params: &Parameters) -> Self {
+        Self::new(solver, params, &solver.solve_timed(input, params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day01::Day01, day15::Day15};
+
+    #[test]
+    fn report() {
+        let timed = Timed {
+            parse: Duration::from_nanos(1500),
+            parts: vec![
+                (Ok("24000".to_owned()), Duration::from_micros(2)),
+                (Ok("45000".to_owned()), Duration::from_micros(3)),
+            ],
+        };
+        let report = SolveReport::new(&Day01, &Parameters::default(), &Ok(timed.clone()));
+        assert_eq!(report.parse, Some(Duration::from_nanos(1500)));
+        assert_eq!(
+            report.parts[1],
+            PartReport {
+                part: 2,
+                answer: Some("45000".to_owned()),
+                error: None,
+                elapsed: Duration::from_micros(3),
+            }
+        );
+        assert_eq!(report.error, None);
+
+        let mut failed = timed;
+        failed.parts[1].0 = Err(SolveError::invalid("no elves"));
+        let report = SolveReport::new(&Day01, &Parameters::default(), &Ok(failed));
+        assert_eq!(report.parts[0].answer, Some("24000".to_owned()));
+        assert_eq!(report.parts[1].answer, None);
+        assert_eq!(report.parts[1].error, Some("no elves".to_owned()));
+        assert_eq!(report.error, Some("no elves".to_owned()));
+
+        let mut params = Parameters::default();
+        params.set("half_grid", 10);
+        let report = SolveReport::run(&Day15, "x", &params);
+        assert_eq!(report.params, params);
+        assert_eq!(report.parse, None);
+        assert_eq!(report.parts, []);
+        assert_eq!(
+            report.error,
+            Some(Day15.solve_timed("x", &params).unwrap_err().to_string())
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use std::{any::Any, collections::BTreeMap};
+
+use crate::{
+    error::SolveError,
+    timing::{time, Timed},
+};
+
+pub struct Parameter {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Parameters(BTreeMap<String, i64>);
+
+impl Parameters {
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.0.insert(name.to_owned(), value);
+    }
+
+    pub fn get(&self, param: &Parameter) -> i64 {
+        *self.0.get(param.name).unwrap_or(&param.default)
+    }
+
+    pub fn given(&self, param: &Parameter) -> Option<i64> {
+        self.0.get(param.name).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+}
+
+// Whatever a solver's `parse` made of the input. Both parts are handed the
+// same one, so solving parses once and the part times leave parsing out.
+pub type Parsed = Box<dyn Any>;
+
+// A copy of the value a solver's `parse` returned, for a part to work on.
+pub fn unpack<T: Clone + 'static>(parsed: &Parsed) -> Result<T, SolveError> {
+    parsed
+        .downcast_ref::<T>()
+        .cloned()
+        .ok_or_else(|| SolveError::invalid("the input was parsed by another solver"))
+}
+
+pub trait Solver: Send + Sync {
+    fn day(&self) -> usize;
+
+    fn title(&self) -> &'static str;
+
+    fn name(&self) -> String {
+        format!("day{:02}", self.day())
+    }
+
+    fn parts(&self) -> usize {
+        2
+    }
+
+    fn params(&self) -> &'static [Parameter] {
+        &[]
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, SolveError>;
+
+    fn part1(&self, parsed: &Parsed, params: &Parameters) -> Result<String, SolveError>;
+
+    fn part2(&self, parsed: &Parsed, params: &Parameters) -> Result<String, SolveError>;
+
+    fn solve_parsed(
+        &self,
+        part: usize,
+        parsed: &Parsed,
+        params: &Parameters,
+    ) -> Result<String, SolveError> {
+        match part {
+            1 => self.part1(parsed, params),
+            2 => self.part2(parsed, params),
+            _ => Err(SolveError::invalid(format!(
+                "day {} has no part {}",
+                self.day(),
+                part
+            ))),
+        }
+    }
+
+    fn solve_part(
+        &self,
+        part: usize,
+        input: &str,
+        params: &Parameters,
+    ) -> Result<String, SolveError> {
+        self.solve_parsed(part, &self.parse(input)?, params)
+    }
+
+    fn solve(&self, input: &str, params: &Parameters) -> Result<(String, String), SolveError> {
+        let parsed = self.parse(input)?;
+        Ok((self.part1(&parsed, params)?, self.part2(&parsed, params)?))
+    }
+
+    fn solve_timed(&self, input: &str, params: &Parameters) -> Result<Timed, SolveError> {
+        let (parsed, parse) = time(|| self.parse(input));
+        let parsed = parsed?;
+        let mut parts = vec![];
+        for part in 1..=self.parts() {
+            let (answer, elapsed) = time(|| self.solve_parsed(part, &parsed, params));
+            parts.push((answer, elapsed));
+        }
+        Ok(Timed { parse, parts })
+    }
+}
+
+pub struct Registry {
+    solvers: BTreeMap<usize, Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self {
+            solvers: BTreeMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, solver: Box<dyn Solver>) {
+        self.solvers.insert(solver.day(), solver);
+    }
+
+    pub fn get(&self, day: usize) -> Option<&dyn Solver> {
+        self.solvers.get(&day).map(|solver| solver.as_ref())
+    }
+
+    pub fn contains(&self, day: usize) -> bool {
+        self.solvers.contains_key(&day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers.values().map(|solver| solver.as_ref())
+    }
+
+    pub fn max_day(&self) -> usize {
+        *self.solvers.keys().next_back().unwrap_or(&0)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(crate::day01::Day01));
+        registry.register(Box::new(crate::day02::Day02));
+        registry.register(Box::new(crate::day03::Day03));
+        registry.register(Box::new(crate::day04::Day04));
+        registry.register(Box::new(crate::day05::Day05));
+        registry.register(Box::new(crate::day06::Day06));
+        registry.register(Box::new(crate::day07::Day07));
+        registry.register(Box::new(crate::day08::Day08));
+        registry.register(Box::new(crate::day09::Day09));
+        registry.register(Box::new(crate::day10::Day10));
+        registry.register(Box::new(crate::day11::Day11));
+        registry.register(Box::new(crate::day12::Day12));
+        registry.register(Box::new(crate::day13::Day13));
+        registry.register(Box::new(crate::day14::Day14));
+        registry.register(Box::new(crate::day15::Day15));
+        registry.register(Box::new(crate::day16::Day16));
+        registry.register(Box::new(crate::day17::Day17));
+        registry.register(Box::new(crate::day18::Day18));
+        registry.register(Box::new(crate::day19::Day19));
+        registry.register(Box::new(crate::day20::Day20));
+        registry.register(Box::new(crate::day21::Day21));
+        registry.register(Box::new(crate::day22::Day22));
+        registry.register(Box::new(crate::day23::Day23));
+        registry.register(Box::new(crate::day24::Day24));
+        registry.register(Box::new(crate::day25::Day25));
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solver for Echo {
+        fn day(&self) -> usize {
+            1
+        }
+
+        fn title(&self) -> &'static str {
+            "Echo"
+        }
+
+        fn parse(&self, input: &str) -> Result<Parsed, SolveError> {
+            Ok(Box::new(input.to_owned()))
+        }
+
+        fn part1(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+            unpack(parsed)
+        }
+
+        fn part2(&self, parsed: &Parsed, _: &Parameters) -> Result<String, SolveError> {
+            Ok(unpack::<String>(parsed)?.chars().rev().collect())
+        }
+    }
+
+    #[test]
+    fn registry() {
+        let registry = Registry::default();
+
+        assert_eq!(registry.max_day(), 25);
+        for (idx, solver) in registry.iter().enumerate() {
+            assert_eq!(solver.day(), idx + 1);
+            assert_eq!(solver.name(), format!("day{:02}", idx + 1));
+        }
+    }
+
+    #[test]
+    fn override_solver() {
+        let mut registry = Registry::default();
+        registry.register(Box::new(Echo));
+
+        let solver = registry.get(1).unwrap();
+        assert_eq!(solver.title(), "Echo");
+        assert_eq!(
+            solver.solve("abc", &Parameters::default()),
+            Ok(("abc".to_owned(), "cba".to_owned()))
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let registry = Registry::default();
+
+        for solver in registry.iter() {
+            for input in ["", "\n\n", "garbage", "1,2\nx y z\n\n#"] {
+                for part in 1..=solver.parts() {
+                    let _ = solver.solve_part(part, input, &Parameters::default());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use instant::Instant;
+
+use crate::error::SolveError;
+
+// Each part keeps its own outcome, a failing part 2 does not hide the answer
+// of part 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timed {
+    pub parse: Duration,
+    pub parts: Vec<(Result<String, SolveError>, Duration)>,
+}
+
+impl Timed {
+    pub fn answer(&self, part: usize) -> Option<&str> {
+        match self.parts.get(part.wrapping_sub(1)) {
+            Some((Ok(answer), _)) => Some(answer),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&SolveError> {
+        self.parts
+            .iter()
+            .find_map(|(result, _)| result.as_ref().err())
+    }
+}
+
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+// Reports give durations in whole nanoseconds.
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_nanos<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_optional_nanos<S: serde::Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serialize_nanos(duration, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1e6;
+    if micros < 1e3 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1e6 {
+        format!("{:.2}ms", micros / 1e3)
+    } else {
+        format!("{:.2}s", micros / 1e6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(1500)), "1.5µs");
+        assert_eq!(format_duration(Duration::from_micros(2345)), "2.35ms");
+        assert_eq!(format_duration(Duration::from_millis(3456)), "3.46s");
+    }
+}
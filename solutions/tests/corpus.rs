@@ -0,0 +1,90 @@
+// Runs every `inputs/dayNN/*.txt` with a matching `*.expected` file as its own
+// test. `AOC_INPUTS` points it at another directory, e.g. one with real puzzle
+// inputs that can't be committed. Arguments not starting with `-` filter the
+// cases by name like the default test harness does.
+use std::{
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process,
+};
+
+use aoc_2022_solutions::{
+    corpus::{check, discover, Expected},
+    Registry,
+};
+
+fn main() {
+    let dir = env::var_os("AOC_INPUTS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs")));
+    let filters: Vec<String> = env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .collect();
+    let cases = match discover(&dir) {
+        Ok(cases) => cases,
+        Err(err) => {
+            println!("no corpus at {}: {}", dir.display(), err);
+            return;
+        }
+    };
+    let cases: Vec<_> = cases
+        .into_iter()
+        .filter(|case| filters.is_empty() || filters.iter().any(|f| case.name.contains(f)))
+        .collect();
+
+    let registry = Registry::default();
+    let mut failures = vec![];
+    let mut ignored = 0;
+    println!("\nrunning {} tests", cases.len());
+    for case in &cases {
+        let result = match (&case.expected, registry.get(case.day)) {
+            (None, _) => None,
+            (Some(_), None) => Some(Err(format!("day {} has no solver", case.day))),
+            (Some(expected), Some(solver)) => {
+                let result = fs::read_to_string(&case.input)
+                    .map_err(|err| format!("could not read {}: {}", case.input.display(), err))
+                    .and_then(|input| {
+                        let text = fs::read_to_string(expected).map_err(|err| {
+                            format!("could not read {}: {}", expected.display(), err)
+                        })?;
+                        let expected = Expected::parse(&text)
+                            .map_err(|err| format!("{}: {}", expected.display(), err))?;
+                        panic::catch_unwind(AssertUnwindSafe(|| check(solver, &input, &expected)))
+                            .unwrap_or_else(|_| Err("solver panicked".to_owned()))
+                    });
+                Some(result)
+            }
+        };
+        let status = match result {
+            None => {
+                ignored += 1;
+                "ignored, no .expected file"
+            }
+            Some(Ok(())) => "ok",
+            Some(Err(err)) => {
+                failures.push((&case.name, err));
+                "FAILED"
+            }
+        };
+        println!("test {} ... {}", case.name, status);
+    }
+
+    if !failures.is_empty() {
+        println!("\nfailures:");
+        for (name, err) in &failures {
+            println!("\n---- {} ----\n{}", name, err);
+        }
+    }
+    println!(
+        "\ntest result: {}. {} passed; {} failed; {} ignored\n",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        cases.len() - failures.len() - ignored,
+        failures.len(),
+        ignored
+    );
+    if !failures.is_empty() {
+        process::exit(101);
+    }
+}
@@ -0,0 +1,449 @@
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind, Read, Write},
+    path::Path,
+};
+
+use aoc_2022_solutions::{
+    bench::{benchmark, to_table},
+    generate::generate,
+    Parameters, Registry, SolveError, SolveReport, Solver,
+};
+
+const USAGE: &str =
+    "usage: aoc_2022 --headless <day> [input file, defaults to stdin] [--<parameter> <value>]... [--json]
+       aoc_2022 --headless bench <input dir with dayNN.txt files> [runs] [--json]
+       aoc_2022 --headless generate <day> [seed, defaults to 0] [scale, defaults to 1]";
+const DEFAULT_RUNS: usize = 10;
+
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Io(String, io::Error),
+    Solve(SolveError),
+    Bench(String, SolveError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Usage(message) => write!(f, "{}\n{}", message, USAGE),
+            Self::Io(path, err) => write!(f, "could not read {}: {}", path, err),
+            Self::Solve(err) => write!(f, "{}", err),
+            Self::Bench(name, err) => write!(f, "{}: {}", name, err),
+        }
+    }
+}
+
+impl From<SolveError> for CliError {
+    fn from(err: SolveError) -> Self {
+        Self::Solve(err)
+    }
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> i32 {
+    let registry = Registry::default();
+    match execute(&registry, args, &mut io::stdout()) {
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            match err {
+                CliError::Usage(_) => 2,
+                _ => 1,
+            }
+        }
+    }
+}
+
+fn execute(
+    registry: &Registry,
+    mut args: impl Iterator<Item = String>,
+    out: &mut impl Write,
+) -> Result<(), CliError> {
+    let day = args
+        .next()
+        .ok_or_else(|| CliError::Usage("missing day".to_owned()))?;
+    if day == "bench" {
+        return bench(registry, args, out);
+    }
+    if day == "generate" {
+        return generate_input(registry, args, out);
+    }
+    let day = parse_day(registry, &day)?;
+    let (flags, args): (Vec<_>, Vec<_>) = args.partition(|arg| arg == "--json");
+    let json = !flags.is_empty();
+    let (path, params) = parse_args(registry.get(day).unwrap(), args.into_iter())?;
+    let input = match path {
+        Some(path) if path != "-" => {
+            fs::read_to_string(&path).map_err(|err| CliError::Io(path, err))?
+        }
+        _ => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| CliError::Io("stdin".to_owned(), err))?;
+            input
+        }
+    };
+    if json {
+        report_day(registry, day, &input, &params, out)
+    } else {
+        run_day(registry, day, &input, &params, out)
+    }
+}
+
+fn parse_day(registry: &Registry, day: &str) -> Result<usize, CliError> {
+    day.parse()
+        .ok()
+        .filter(|day| registry.contains(*day))
+        .ok_or_else(|| {
+            CliError::Usage(format!(
+                "day must be between 1 and {}, found {:?}",
+                registry.max_day(),
+                day
+            ))
+        })
+}
+
+// Splits the arguments after the day into the optional input path and the
+// solver parameters, given as `--name value` or `--name=value`.
+fn parse_args(
+    solver: &dyn Solver,
+    mut args: impl Iterator<Item = String>,
+) -> Result<(Option<String>, Parameters), CliError> {
+    let mut path = None;
+    let mut params = Parameters::default();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--") {
+            Some(flag) => {
+                let (name, value) = match flag.split_once('=') {
+                    Some((name, value)) => (name.to_owned(), value.to_owned()),
+                    None => {
+                        let value = args.next().ok_or_else(|| {
+                            CliError::Usage(format!("missing value for --{}", flag))
+                        })?;
+                        (flag.to_owned(), value)
+                    }
+                };
+                set_param(solver, &mut params, &name, &value)?;
+            }
+            None if path.is_none() => path = Some(arg),
+            None => return Err(CliError::Usage(format!("unexpected argument {:?}", arg))),
+        }
+    }
+    Ok((path, params))
+}
+
+fn set_param(
+    solver: &dyn Solver,
+    params: &mut Parameters,
+    name: &str,
+    value: &str,
+) -> Result<(), CliError> {
+    let param = solver
+        .params()
+        .iter()
+        .find(|param| param.name == name)
+        .ok_or_else(|| {
+            let names: Vec<String> = solver
+                .params()
+                .iter()
+                .map(|param| format!("--{}", param.name))
+                .collect();
+            CliError::Usage(if names.is_empty() {
+                format!("day {} takes no parameters, found --{}", solver.day(), name)
+            } else {
+                format!(
+                    "day {} has no parameter --{}, expected one of {}",
+                    solver.day(),
+                    name,
+                    names.join(", ")
+                )
+            })
+        })?;
+    let value = value.parse().map_err(|_| {
+        CliError::Usage(format!("--{} must be an integer, found {:?}", name, value))
+    })?;
+    params.set(param.name, value);
+    Ok(())
+}
+
+fn run_day(
+    registry: &Registry,
+    day: usize,
+    input: &str,
+    params: &Parameters,
+    out: &mut impl Write,
+) -> Result<(), CliError> {
+    let solver = registry.get(day).unwrap();
+    let parsed = solver.parse(input)?;
+    for part in 1..=solver.parts() {
+        let answer = solver.solve_parsed(part, &parsed, params)?;
+        writeln!(out, "Part {}: {}", part, answer)
+            .map_err(|err| CliError::Io("stdout".to_owned(), err))?;
+    }
+    Ok(())
+}
+
+// Prints a `SolveReport` as JSON, also when solving fails so the error ends up
+// in the report.
+fn report_day(
+    registry: &Registry,
+    day: usize,
+    input: &str,
+    params: &Parameters,
+    out: &mut impl Write,
+) -> Result<(), CliError> {
+    let solver = registry.get(day).unwrap();
+    let result = solver.solve_timed(input, params);
+    let report = SolveReport::new(solver, params, &result);
+    let json = serde_json::to_string(&report)
+        .map_err(|err| CliError::Io("stdout".to_owned(), err.into()))?;
+    writeln!(out, "{}", json).map_err(|err| CliError::Io("stdout".to_owned(), err))?;
+    match result.map_err(CliError::Solve)?.error() {
+        Some(err) => Err(CliError::Solve(err.clone())),
+        None => Ok(()),
+    }
+}
+
+fn bench(
+    registry: &Registry,
+    args: impl Iterator<Item = String>,
+    out: &mut impl Write,
+) -> Result<(), CliError> {
+    let (flags, mut args): (Vec<_>, Vec<_>) = args.partition(|arg| arg == "--json");
+    let json = !flags.is_empty();
+    args.reverse();
+    let dir = args
+        .pop()
+        .ok_or_else(|| CliError::Usage("missing input directory".to_owned()))?;
+    let runs = match args.pop() {
+        Some(runs) => runs.parse().ok().filter(|runs| *runs > 0).ok_or_else(|| {
+            CliError::Usage(format!("runs must be a positive number, found {:?}", runs))
+        })?,
+        None => DEFAULT_RUNS,
+    };
+    if let Some(arg) = args.pop() {
+        return Err(CliError::Usage(format!("unexpected argument {:?}", arg)));
+    }
+
+    let mut benchmarks = vec![];
+    for solver in registry.iter() {
+        let path = Path::new(&dir).join(format!("{}.txt", solver.name()));
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                eprintln!("skipping {}: {} not found", solver.name(), path.display());
+                continue;
+            }
+            Err(err) => return Err(CliError::Io(path.display().to_string(), err)),
+        };
+        let result = benchmark(solver, &input, &Parameters::default(), runs)
+            .map_err(|err| CliError::Bench(solver.name(), err))?;
+        benchmarks.push(result);
+    }
+
+    let output = if json {
+        serde_json::to_string(&benchmarks)
+            .map_err(|err| CliError::Io("stdout".to_owned(), err.into()))?
+    } else {
+        to_table(&benchmarks)
+    };
+    writeln!(out, "{}", output.trim_end()).map_err(|err| CliError::Io("stdout".to_owned(), err))
+}
+
+fn generate_input(
+    registry: &Registry,
+    mut args: impl Iterator<Item = String>,
+    out: &mut impl Write,
+) -> Result<(), CliError> {
+    let day = args
+        .next()
+        .ok_or_else(|| CliError::Usage("missing day".to_owned()))?;
+    let day = parse_day(registry, &day)?;
+    let seed = match args.next() {
+        Some(seed) => seed
+            .parse()
+            .map_err(|_| CliError::Usage(format!("seed must be a number, found {:?}", seed)))?,
+        None => 0,
+    };
+    let scale = match args.next() {
+        Some(scale) => scale
+            .parse()
+            .ok()
+            .filter(|scale| *scale > 0)
+            .ok_or_else(|| {
+                CliError::Usage(format!(
+                    "scale must be a positive number, found {:?}",
+                    scale
+                ))
+            })?,
+        None => 1,
+    };
+    if let Some(arg) = args.next() {
+        return Err(CliError::Usage(format!("unexpected argument {:?}", arg)));
+    }
+    let input = generate(day, seed, scale)
+        .ok_or_else(|| CliError::Usage(format!("no generator for day {}", day)))?;
+    write!(out, "{}", input).map_err(|err| CliError::Io("stdout".to_owned(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2022_solutions::{
+        bench::{Benchmark, Stats},
+        report::VERSION,
+        Timed,
+    };
+    use indoc::indoc;
+    use std::time::Duration;
+
+    #[test]
+    fn headless() {
+        let registry = Registry::default();
+        let input = indoc! {"
+            1000
+            2000
+            3000
+
+            4000
+
+            5000
+            6000
+
+            7000
+            8000
+            9000
+
+            10000
+        "};
+        let mut out = vec![];
+        run_day(&registry, 1, input, &Parameters::default(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Part 1: 24000\nPart 2: 45000\n"
+        );
+
+        let err = run_day(&registry, 1, "x", &Parameters::default(), &mut vec![]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: invalid value \"x\": invalid digit found in string"
+        );
+
+        let args = ["26".to_owned()].into_iter();
+        let err = execute(&registry, args, &mut vec![]).unwrap_err();
+        assert!(matches!(err, CliError::Usage(_)));
+    }
+
+    #[test]
+    fn json_report() {
+        let registry = Registry::default();
+        let mut out = vec![];
+        let err = report_day(&registry, 1, "x", &Parameters::default(), &mut out).unwrap_err();
+        assert!(matches!(err, CliError::Solve(_)));
+        let json = String::from_utf8(out).unwrap();
+        assert!(json.starts_with("{\"day\":1,\"title\":\"Calorie Counting\""));
+        assert!(json.ends_with(
+            "\"parts\":[],\"error\":\"line 1, column 1: invalid value \\\"x\\\": \
+            invalid digit found in string\"}\n"
+        ));
+    }
+
+    #[test]
+    fn json_format() {
+        let timed = Timed {
+            parse: Duration::from_nanos(1500),
+            parts: vec![
+                (Ok("26".to_owned()), Duration::from_micros(2)),
+                (
+                    Err(SolveError::invalid("a \"b\"\\\n\u{1}")),
+                    Duration::from_micros(3),
+                ),
+            ],
+        };
+        let registry = Registry::default();
+        let mut params = Parameters::default();
+        params.set("half_grid", 10);
+        let report = SolveReport::new(registry.get(15).unwrap(), &params, &Ok(timed));
+        assert_eq!(
+            serde_json::to_string(&[report]).unwrap(),
+            format!(
+                "[{{\"day\":15,\"title\":\"Beacon Exclusion Zone\",\"version\":\"{}\",\
+                \"params\":{{\"half_grid\":10}},\"parse_ns\":1500,\"parts\":[\
+                {{\"part\":1,\"answer\":\"26\",\"error\":null,\"elapsed_ns\":2000}},\
+                {{\"part\":2,\"answer\":null,\"error\":\"a \\\"b\\\"\\\\\\n\\u0001\",\
+                \"elapsed_ns\":3000}}],\
+                \"error\":\"a \\\"b\\\"\\\\\\n\\u0001\"}}]",
+                VERSION
+            )
+        );
+
+        let stats = Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_micros(2500),
+            max: Duration::from_millis(4),
+        };
+        let benchmarks = [Benchmark {
+            day: 25,
+            parse: stats,
+            parts: vec![stats],
+        }];
+        assert_eq!(
+            serde_json::to_string(&benchmarks).unwrap(),
+            "[{\"day\":25,\
+            \"parse\":{\"min_ns\":1000000,\"median_ns\":2500000,\"max_ns\":4000000},\
+            \"parts\":[{\"min_ns\":1000000,\"median_ns\":2500000,\"max_ns\":4000000}]}]"
+        );
+    }
+
+    #[test]
+    fn bench_usage() {
+        let registry = Registry::default();
+        let args = ["bench", "inputs", "0"].map(str::to_owned).into_iter();
+        let err = execute(&registry, args, &mut vec![]).unwrap_err();
+        assert!(matches!(err, CliError::Usage(_)));
+    }
+
+    #[test]
+    fn generate_command() {
+        let registry = Registry::default();
+        let mut out = vec![];
+        let args = ["generate", "1", "7"].map(str::to_owned).into_iter();
+        execute(&registry, args, &mut out).unwrap();
+        let input = String::from_utf8(out).unwrap();
+        assert_eq!(Some(input), generate(1, 7, 1));
+        for args in [&["generate", "1", "x"][..], &["generate", "1", "7", "0"]] {
+            let args = args.iter().map(|arg| arg.to_string());
+            let err = execute(&registry, args, &mut vec![]).unwrap_err();
+            assert!(matches!(err, CliError::Usage(_)));
+        }
+    }
+
+    #[test]
+    fn parameters() {
+        let registry = Registry::default();
+        let day15 = registry.get(15).unwrap();
+        let args = ["sample.txt", "--half_grid", "10"].map(str::to_owned);
+        let (path, params) = parse_args(day15, args.into_iter()).unwrap();
+        assert_eq!(path.as_deref(), Some("sample.txt"));
+        assert_eq!(params.iter().collect::<Vec<_>>(), [("half_grid", 10)]);
+        let args = ["--half_grid=-3".to_owned()];
+        let (path, params) = parse_args(day15, args.into_iter()).unwrap();
+        assert_eq!(path, None);
+        assert_eq!(params.iter().collect::<Vec<_>>(), [("half_grid", -3)]);
+
+        let err = parse_args(day15, ["--size=4".to_owned()].into_iter()).unwrap_err();
+        assert_eq!(
+            err.to_string().lines().next().unwrap(),
+            "day 15 has no parameter --size, expected one of --half_grid"
+        );
+        let err = parse_args(day15, ["--half_grid".to_owned()].into_iter()).unwrap_err();
+        assert!(matches!(err, CliError::Usage(_)));
+        let err = parse_args(registry.get(1).unwrap(), ["--x=1".to_owned()].into_iter());
+        assert_eq!(
+            err.unwrap_err().to_string().lines().next().unwrap(),
+            "day 1 takes no parameters, found --x"
+        );
+    }
+}
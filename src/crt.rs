@@ -0,0 +1,173 @@
+use aoc_2022_solutions::day10::Crt;
+use bevy::{prelude::*, ui::FocusPolicy};
+
+const WIDTH: usize = 40;
+const PIXEL: f32 = 14.0;
+// Cycles drawn per second, so a screen of 240 takes four seconds.
+const SPEED: f32 = 60.0;
+const DARK: Color = Color::rgb(0.06, 0.1, 0.07);
+const LIT: Color = Color::rgb(0.45, 1.0, 0.55);
+const BEAM: Color = Color::rgb(1.0, 1.0, 1.0);
+const SPRITE: Color = Color::rgb(0.9, 0.6, 0.2);
+
+// The beam drawing the day 10 screen, along with the pixels of the screen and
+// of the row below it showing where the sprite is.
+#[derive(Resource)]
+pub struct Beam {
+    crt: Crt,
+    pixels: Vec<Entity>,
+    sprite: Vec<Entity>,
+    label: Entity,
+    // The pixel under the beam, and whether it lit up.
+    drawn: Option<(Entity, bool)>,
+    due: f32,
+    done: bool,
+}
+
+fn pixel_bundle() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(PIXEL), Val::Px(PIXEL)),
+            margin: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        background_color: DARK.into(),
+        focus_policy: FocusPolicy::Pass,
+        ..default()
+    }
+}
+
+fn row_bundle(margin: UiRect) -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            margin,
+            ..default()
+        },
+        focus_policy: FocusPolicy::Pass,
+        ..default()
+    }
+}
+
+// Takes the place of the answer text, with every pixel dark until the beam
+// gets to it.
+pub fn build_screen(parent: &mut ChildBuilder, font: &Handle<Font>, crt: Crt, rows: usize) -> Beam {
+    let mut pixels = vec![];
+    let mut sprite = vec![];
+    let mut label = None;
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(30.0)),
+                ..default()
+            },
+            focus_policy: FocusPolicy::Pass,
+            ..default()
+        })
+        .with_children(|parent| {
+            for _ in 0..rows {
+                parent
+                    .spawn(row_bundle(UiRect::default()))
+                    .with_children(|parent| {
+                        for _ in 0..WIDTH {
+                            pixels.push(parent.spawn(pixel_bundle()).id());
+                        }
+                    });
+            }
+            parent
+                .spawn(row_bundle(UiRect::top(Val::Px(PIXEL))))
+                .with_children(|parent| {
+                    for _ in 0..WIDTH {
+                        sprite.push(parent.spawn(pixel_bundle()).id());
+                    }
+                });
+            label = Some(
+                parent
+                    .spawn(
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 25.0,
+                                color: Color::rgb(0.6, 0.6, 0.6),
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::all(Val::Px(10.0)),
+                            ..default()
+                        }),
+                    )
+                    .id(),
+            );
+        });
+    Beam {
+        crt,
+        pixels,
+        sprite,
+        label: label.unwrap(),
+        drawn: None,
+        due: 0.0,
+        done: false,
+    }
+}
+
+// Draws the cycles due since the last frame, showing the sprite where the X
+// register was during the last one.
+pub fn beam_system(
+    time: Res<Time>,
+    beam: Option<ResMut<Beam>>,
+    mut backgrounds: Query<&mut BackgroundColor>,
+    mut texts: Query<&mut Text>,
+) {
+    let mut beam = match beam {
+        Some(beam) => beam,
+        None => return,
+    };
+    if beam.done {
+        return;
+    }
+    let beam = &mut *beam;
+    let mut paint = |entity: Entity, color: Color| {
+        if let Ok(mut background) = backgrounds.get_mut(entity) {
+            *background = color.into();
+        }
+    };
+    beam.due += time.delta_seconds() * SPEED;
+    let mut last = None;
+    while beam.due >= 1.0 {
+        beam.due -= 1.0;
+        let step = beam.crt.step();
+        // The pixel under the beam gets its own color back, the last one too
+        // once the program ends.
+        if let Some((entity, lit)) = beam.drawn.take() {
+            paint(entity, if lit { LIT } else { DARK });
+        }
+        // The answer came from the same program, so it can't fail here.
+        let pixel = match step {
+            Ok(Some(pixel)) => pixel,
+            _ => {
+                beam.done = true;
+                break;
+            }
+        };
+        if let Some(entity) = beam.pixels.get(pixel.row * WIDTH + pixel.column) {
+            paint(*entity, BEAM);
+            beam.drawn = Some((*entity, pixel.lit));
+        }
+        last = Some(pixel);
+    }
+    if let Some(pixel) = last {
+        for (column, entity) in beam.sprite.iter().enumerate() {
+            let covered = (column as isize).abs_diff(pixel.sprite) <= 1;
+            paint(*entity, if covered { SPRITE } else { DARK });
+        }
+        if let Ok(mut text) = texts.get_mut(beam.label) {
+            text.sections[0].value = format!("cycle {} | X = {}", pixel.cycle, pixel.sprite);
+        }
+    }
+}
+
+pub fn crt_cleanup(mut commands: Commands) {
+    commands.remove_resource::<Beam>();
+}
@@ -0,0 +1,383 @@
+use crate::{
+    days::{set_clipboard, Solvers},
+    solving::{SolveResult, SolveTask},
+    store::{Store, Verdict},
+    GameState,
+};
+use aoc_2022_solutions::{timing::format_duration, Parameters, SolveReport};
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContext};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    bevy_egui::EguiClipboard,
+    std::{fs, io::ErrorKind, path::Path},
+};
+
+const HEADERS: [&str; 6] = ["Day", "Title", "Part 1", "Part 2", "Time", "Status"];
+#[cfg(not(target_arch = "wasm32"))]
+const FOLDER_HINT: &str =
+    "Drop a folder with dayNN.txt files onto the window to solve those instead";
+
+pub struct DashboardPlugin;
+
+impl Plugin for DashboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Dashboard>()
+            .add_system_set(SystemSet::on_enter(GameState::Dashboard).with_system(dashboard_setup))
+            .add_system_set(
+                SystemSet::on_update(GameState::Dashboard)
+                    .with_system(solve_system)
+                    .with_system(dashboard_system),
+            )
+            .add_system_set(
+                SystemSet::on_exit(GameState::Dashboard).with_system(dashboard_cleanup),
+            );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_system_set(
+            SystemSet::on_update(GameState::Dashboard).with_system(folder_input_system),
+        );
+    }
+}
+
+enum Outcome {
+    Queued,
+    Solving,
+    Solved {
+        answers: Vec<(String, Verdict)>,
+        elapsed: Duration,
+    },
+    Failed(String),
+}
+
+struct Row {
+    day: usize,
+    title: &'static str,
+    outcome: Outcome,
+}
+
+#[derive(Resource, Default)]
+struct Dashboard {
+    source: String,
+    rows: Vec<Row>,
+    reports: Vec<SolveReport>,
+    queue: VecDeque<(usize, String, Parameters)>,
+    running: Option<(usize, Parameters, SolveTask)>,
+}
+
+impl Dashboard {
+    fn load(
+        &mut self,
+        source: String,
+        inputs: Vec<(usize, String, Parameters)>,
+        solvers: &Solvers,
+    ) {
+        self.source = source;
+        self.rows = inputs
+            .iter()
+            .map(|(day, _, _)| Row {
+                day: *day,
+                title: solvers.get(*day).unwrap().title(),
+                outcome: Outcome::Queued,
+            })
+            .collect();
+        self.reports.clear();
+        self.queue = inputs.into();
+        self.running = None;
+    }
+
+    fn row(&mut self, day: usize) -> &mut Row {
+        self.rows.iter_mut().find(|row| row.day == day).unwrap()
+    }
+}
+
+fn dashboard_setup(mut dashboard: ResMut<Dashboard>, solvers: Res<Solvers>, store: Res<Store>) {
+    let inputs = solvers
+        .iter()
+        .filter_map(|solver| {
+            let record = store.get(solver.day())?;
+            Some((solver.day(), record.input.clone(), record.parameters()))
+        })
+        .collect();
+    dashboard.load("saved inputs".to_owned(), inputs, &solvers);
+}
+
+fn dashboard_cleanup(mut dashboard: ResMut<Dashboard>) {
+    *dashboard = Dashboard::default();
+}
+
+// Days are solved one after the other, the web build has a single solver
+// worker.
+fn solve_system(mut dashboard: ResMut<Dashboard>, solvers: Res<Solvers>, store: Res<Store>) {
+    if let Some((day, params, task)) = &mut dashboard.running {
+        let day = *day;
+        match task.poll() {
+            Some(result) => {
+                let report = SolveReport::new(solvers.get(day).unwrap(), params, &result);
+                dashboard.reports.push(report);
+                dashboard.row(day).outcome = outcome(day, result, &store);
+                dashboard.running = None;
+            }
+            None => return,
+        }
+    }
+    if let Some((day, input, params)) = dashboard.queue.pop_front() {
+        dashboard.row(day).outcome = Outcome::Solving;
+        let task = SolveTask::spawn(Arc::clone(&solvers), day, input, params.clone());
+        dashboard.running = Some((day, params, task));
+    }
+}
+
+fn outcome(day: usize, result: SolveResult, store: &Store) -> Outcome {
+    let timed = match result {
+        Ok(timed) => timed,
+        Err(err) => return Outcome::Failed(err.to_string()),
+    };
+    // The report of the run still has the answers of the parts that worked.
+    if let Some(err) = timed.error() {
+        return Outcome::Failed(err.to_string());
+    }
+    let parts: Duration = timed.parts.iter().map(|(_, elapsed)| *elapsed).sum();
+    Outcome::Solved {
+        elapsed: timed.parse + parts,
+        answers: (1..)
+            .zip(timed.parts)
+            .filter_map(|(part, (result, _))| {
+                let answer = result.ok()?;
+                let verdict = store.verify(day, part, &answer);
+                Some((answer, verdict))
+            })
+            .collect(),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn folder_input_system(
+    mut events: EventReader<FileDragAndDrop>,
+    mut dashboard: ResMut<Dashboard>,
+    solvers: Res<Solvers>,
+) {
+    for event in events.iter() {
+        if let FileDragAndDrop::DroppedFile { path_buf, .. } = event {
+            if path_buf.is_dir() {
+                let inputs = read_folder(path_buf, &solvers);
+                dashboard.load(path_buf.display().to_string(), inputs, &solvers);
+            }
+        }
+    }
+}
+
+// Uses the same `dayNN.txt` names as the headless `bench` command.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_folder(dir: &Path, solvers: &Solvers) -> Vec<(usize, String, Parameters)> {
+    let mut inputs = vec![];
+    for solver in solvers.iter() {
+        let path = dir.join(format!("{}.txt", solver.name()));
+        match fs::read_to_string(&path) {
+            Ok(input) => inputs.push((solver.day(), input, Parameters::default())),
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => warn!("could not read {}: {}", path.display(), err),
+        }
+    }
+    inputs
+}
+
+fn dashboard_system(
+    mut egui_context: ResMut<EguiContext>,
+    #[cfg(not(target_arch = "wasm32"))] mut egui_clipboard: ResMut<EguiClipboard>,
+    mut game_state: ResMut<State<GameState>>,
+    keys: Res<Input<KeyCode>>,
+    dashboard: Res<Dashboard>,
+) {
+    let mut back = keys.just_pressed(KeyCode::Escape);
+    let mut export = None;
+    egui::CentralPanel::default().show(egui_context.ctx_mut(), |ui| {
+        ui.horizontal(|ui| {
+            back |= ui.button("Back").clicked();
+            if ui.button("Copy Markdown").clicked() {
+                export = Some(to_markdown(&dashboard.rows));
+            }
+            if ui.button("Copy CSV").clicked() {
+                export = Some(to_csv(&dashboard.rows));
+            }
+            if ui.button("Copy JSON").clicked() {
+                export = serde_json::to_string(&dashboard.reports).ok();
+            }
+        });
+        ui.label(format!("Inputs: {}", dashboard.source));
+        #[cfg(not(target_arch = "wasm32"))]
+        ui.weak(FOLDER_HINT);
+        if dashboard.rows.is_empty() {
+            ui.label("No inputs found, solve some days first");
+        }
+        ui.separator();
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("dashboard")
+                .striped(true)
+                .num_columns(HEADERS.len())
+                .show(ui, |ui| {
+                    for header in HEADERS {
+                        ui.strong(header);
+                    }
+                    ui.end_row();
+                    for row in &dashboard.rows {
+                        let [day, title, part1, part2, time, status] = cells(row);
+                        ui.label(day);
+                        ui.label(title);
+                        for (part, text) in [(0, part1), (1, part2)] {
+                            let color = match &row.outcome {
+                                Outcome::Solved { answers, .. } => match answers.get(part) {
+                                    Some((_, Verdict::Correct)) => egui::Color32::LIGHT_GREEN,
+                                    Some((_, Verdict::Wrong(_))) => egui::Color32::LIGHT_RED,
+                                    _ => ui.visuals().text_color(),
+                                },
+                                _ => ui.visuals().text_color(),
+                            };
+                            ui.colored_label(color, egui::RichText::new(text).monospace());
+                        }
+                        ui.label(time);
+                        match &row.outcome {
+                            Outcome::Failed(_) => {
+                                ui.colored_label(egui::Color32::LIGHT_RED, status)
+                            }
+                            _ => ui.label(status),
+                        };
+                        ui.end_row();
+                    }
+                });
+        });
+    });
+
+    if let Some(export) = export {
+        #[cfg(target_arch = "wasm32")]
+        set_clipboard(&export);
+        #[cfg(not(target_arch = "wasm32"))]
+        set_clipboard(&export, &mut egui_clipboard);
+    }
+    if back {
+        game_state.set(GameState::Menu).ok();
+    }
+}
+
+fn cells(row: &Row) -> [String; 6] {
+    let mut cells = [
+        row.day.to_string(),
+        row.title.to_owned(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+    ];
+    match &row.outcome {
+        Outcome::Queued => cells[5] = "queued".to_owned(),
+        Outcome::Solving => cells[5] = "solving".to_owned(),
+        Outcome::Failed(err) => cells[5] = err.clone(),
+        Outcome::Solved { answers, elapsed } => {
+            for (cell, (answer, _)) in cells[2..4].iter_mut().zip(answers) {
+                *cell = answer.clone();
+            }
+            cells[4] = format_duration(*elapsed);
+            cells[5] = status(answers);
+        }
+    }
+    cells
+}
+
+fn status(answers: &[(String, Verdict)]) -> String {
+    if answers
+        .iter()
+        .all(|(_, verdict)| *verdict == Verdict::Correct)
+    {
+        return "verified".to_owned();
+    }
+    answers
+        .iter()
+        .map(|(_, verdict)| match verdict {
+            Verdict::Unknown => "unverified",
+            Verdict::Correct => "correct",
+            Verdict::Wrong(_) => "wrong",
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn to_markdown(rows: &[Row]) -> String {
+    let line = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+    let mut table = line(&HEADERS.map(str::to_owned));
+    table.push_str("|---:|---|---|---|---:|---|\n");
+    for row in rows {
+        let cells = cells(row).map(|cell| cell.replace('|', "\\|").replace('\n', " "));
+        table.push_str(&line(&cells));
+    }
+    table
+}
+
+fn to_csv(rows: &[Row]) -> String {
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .map(|cell| {
+                if cell.contains([',', '"', '\n']) {
+                    format!("\"{}\"", cell.replace('"', "\"\""))
+                } else {
+                    cell.clone()
+                }
+            })
+            .collect();
+        format!("{}\n", cells.join(","))
+    };
+    let mut table = line(&HEADERS.map(str::to_owned));
+    for row in rows {
+        table.push_str(&line(&cells(row)));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export() {
+        let rows = [
+            Row {
+                day: 1,
+                title: "Calorie Counting",
+                outcome: Outcome::Solved {
+                    answers: vec![
+                        ("24000".to_owned(), Verdict::Correct),
+                        ("45000".to_owned(), Verdict::Unknown),
+                    ],
+                    elapsed: Duration::from_micros(1500),
+                },
+            },
+            Row {
+                day: 5,
+                title: "Supply Stacks",
+                outcome: Outcome::Failed("line 1, column 2: expected \"move\"".to_owned()),
+            },
+            Row {
+                day: 12,
+                title: "Hill Climbing Algorithm",
+                outcome: Outcome::Queued,
+            },
+        ];
+        assert_eq!(
+            to_markdown(&rows),
+            "| Day | Title | Part 1 | Part 2 | Time | Status |\n\
+             |---:|---|---|---|---:|---|\n\
+             | 1 | Calorie Counting | 24000 | 45000 | 1.50ms | correct, unverified |\n\
+             | 5 | Supply Stacks |  |  |  | line 1, column 2: expected \"move\" |\n\
+             | 12 | Hill Climbing Algorithm |  |  |  | queued |\n"
+        );
+        assert_eq!(
+            to_csv(&rows),
+            "Day,Title,Part 1,Part 2,Time,Status\n\
+             1,Calorie Counting,24000,45000,1.50ms,\"correct, unverified\"\n\
+             5,Supply Stacks,,,,\"line 1, column 2: expected \"\"move\"\"\"\n\
+             12,Hill Climbing Algorithm,,,,queued\n"
+        );
+    }
+}
@@ -1,45 +1,50 @@
-use crate::{despawn_screen, DaySelectState, GameState, HOVER_BUTTON, PRESSED_BUTTON};
+use crate::{
+    crt::{self, Beam},
+    despawn_screen,
+    editor::{EditorAction, InputEditor},
+    solving::{SolveResult, SolveTask},
+    store::{DayRecord, Store, Verdict},
+    visualize::Scene,
+    DaySelectState, GameState, HOVER_BUTTON, PRESSED_BUTTON,
+};
+use aoc_2022_solutions::{
+    day10::Crt, timing::format_duration, Parameters, Registry, SolveError, SolveReport,
+};
 use bevy::prelude::*;
-use bevy_egui::EguiClipboard;
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::JsCast;
+use bevy_egui::{EguiClipboard, EguiContext};
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+use std::sync::Arc;
 #[cfg(target_arch = "wasm32")]
 use web_sys::HtmlTextAreaElement;
+#[cfg(target_arch = "wasm32")]
+use {
+    crate::{web_file, web_route},
+    wasm_bindgen::JsCast,
+};
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-
-pub const MAX_DAY: usize = 25;
 const BUTTON_BACKGROUND: Color = Color::rgb(0.2235, 0.2196, 0.2);
 const LABEL_BACKGROUND: Color = Color::rgb(0.0549, 0.1765, 0.2549);
+const ERROR_BACKGROUND: Color = Color::rgb(0.3529, 0.0745, 0.0745);
+const CORRECT_TEXT: Color = Color::rgb(0.4, 0.9, 0.4);
+const WRONG_TEXT: Color = Color::rgb(1.0, 0.4, 0.4);
+#[cfg(not(target_arch = "wasm32"))]
+const FILE_HINT: &str = "or drop a .txt file onto the window";
+#[cfg(target_arch = "wasm32")]
+const FILE_HINT: &str = "or drop a .txt file onto the page";
+const SPINNER: [&str; 4] = ["|", "/", "-", "\\"];
 pub struct DaysPlugin;
 
 impl Plugin for DaysPlugin {
     fn build(&self, app: &mut App) {
-        app.add_state(DayState::Disabled)
+        app.insert_resource(Store::load())
+            .init_resource::<Solvers>()
+            .init_resource::<Solution>()
+            .init_resource::<DayParameters>()
+            .init_resource::<Report>()
+            .init_resource::<SharedInput>()
+            .init_resource::<InputEditor>()
+            .add_state(DayState::Disabled)
             .add_state(InputState("".to_owned()))
             .add_state(Part1State("".to_owned()))
             .add_state(Part2State("".to_owned()))
@@ -48,26 +53,62 @@ impl Plugin for DaysPlugin {
                 SystemSet::on_exit(GameState::Day).with_system(despawn_screen::<OnDayScreen>),
             )
             .add_system_set(SystemSet::on_enter(DayState::Input).with_system(day_input_setup))
+            .add_system_set(SystemSet::on_update(DayState::Input).with_system(file_input_system))
             .add_system_set(
                 SystemSet::on_exit(DayState::Input).with_system(despawn_screen::<OnDayInputScreen>),
             )
+            .add_system_set(SystemSet::on_enter(DayState::Edit).with_system(day_edit_setup))
+            .add_system_set(SystemSet::on_update(DayState::Edit).with_system(input_editor_system))
+            .add_system_set(SystemSet::on_enter(DayState::Solving).with_system(day_solving_setup))
+            .add_system_set(SystemSet::on_update(DayState::Solving).with_system(solving_system))
+            .add_system_set(
+                SystemSet::on_exit(DayState::Solving)
+                    .with_system(despawn_screen::<OnDaySolvingScreen>),
+            )
             .add_system_set(SystemSet::on_enter(DayState::Show).with_system(day_show_setup))
+            .add_system_set(SystemSet::on_update(DayState::Show).with_system(crt::beam_system))
             .add_system_set(
-                SystemSet::on_exit(DayState::Show).with_system(despawn_screen::<OnDayShowScreen>),
+                SystemSet::on_exit(DayState::Show)
+                    .with_system(despawn_screen::<OnDayShowScreen>)
+                    .with_system(crt::crt_cleanup),
             )
             .add_system_set(SystemSet::on_update(GameState::Day).with_system(exit_system));
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_startup_system(web_file::listen)
+            .add_startup_system(open_route)
+            .add_system(update_route);
     }
 }
 
+#[derive(Resource, Deref, Default)]
+pub struct Solvers(Arc<Registry>);
+
+#[derive(Resource, Default)]
+struct Solution(Option<SolveResult>);
+
+#[derive(Resource, Default)]
+struct DayParameters(Parameters);
+
+// The result on the show screen, for the "Copy JSON" button.
+#[derive(Resource, Default)]
+struct Report(Option<SolveReport>);
+
+// An input from a shared link, solved as soon as its day opens.
+#[derive(Resource, Default)]
+struct SharedInput(Option<String>);
+
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
 enum DayState {
     Disabled,
     Input,
+    Edit,
+    Solving,
     Show,
 }
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
-struct InputState(String);
+pub struct InputState(pub String);
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
 struct Part1State(String);
@@ -75,12 +116,22 @@ struct Part1State(String);
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
 struct Part2State(String);
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy)]
 enum ButtonAction {
     Exit,
     Paste,
+    Cancel,
+    Rerun,
+    EditInput,
     CopyPart1,
     CopyPart2,
+    CopyAnswers,
+    CopyReport,
+    #[cfg(target_arch = "wasm32")]
+    ShareLink,
+    AcceptPart1,
+    AcceptPart2,
+    Visualize,
 }
 
 #[derive(Component)]
@@ -89,18 +140,44 @@ struct OnDayScreen;
 #[derive(Component)]
 struct OnDayInputScreen;
 
+#[derive(Component)]
+struct OnDaySolvingScreen;
+
+#[derive(Component)]
+struct SolvingLabel;
+
 #[derive(Component)]
 struct OnDayShowScreen;
 
+#[allow(clippy::too_many_arguments)]
 fn day_setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     day_select_state: Res<State<DaySelectState>>,
     mut day_state: ResMut<State<DayState>>,
+    mut input_state: ResMut<State<InputState>>,
+    mut solution: ResMut<Solution>,
+    mut day_params: ResMut<DayParameters>,
+    mut shared_input: ResMut<SharedInput>,
+    solvers: Res<Solvers>,
+    store: Res<Store>,
 ) {
-    day_state.set(DayState::Input).unwrap();
     let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
     let day = day_select_state.current().0;
+    if let Some(input) = shared_input.0.take() {
+        input_state.set(InputState(input)).ok();
+        day_params.0 = Parameters::default();
+        day_state.set(DayState::Solving).unwrap();
+    } else if let Some(record) = store.get(day) {
+        input_state.set(InputState(record.input.clone())).ok();
+        solution.0 = Some(Ok(record.timed()));
+        day_params.0 = record.parameters();
+        day_state.set(DayState::Show).unwrap();
+    } else {
+        day_params.0 = Parameters::default();
+        day_state.set(DayState::Input).unwrap();
+    }
+    let title = solvers.get(day).unwrap().title();
     commands
         .spawn((
             NodeBundle {
@@ -164,7 +241,7 @@ fn day_setup(
                             ));
                         });
                     parent.spawn(TextBundle::from_section(
-                        format!("Day {}", day),
+                        format!("Day {}: {}", day, title),
                         TextStyle {
                             font: font.clone(),
                             font_size: 50.0,
@@ -236,55 +313,242 @@ fn day_input_setup(
                                 ..default()
                             }),
                         );
+                        parent.spawn(
+                            TextBundle::from_section(
+                                FILE_HINT,
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 25.0,
+                                    color: Color::rgb(0.6, 0.6, 0.6),
+                                },
+                            )
+                            .with_style(Style {
+                                margin: UiRect::all(Val::Px(20.0)),
+                                ..default()
+                            }),
+                        );
                     });
             });
     });
 }
 
-fn day_show_setup(
+#[cfg(not(target_arch = "wasm32"))]
+fn file_input_system(
+    mut events: EventReader<FileDragAndDrop>,
+    mut day_state: ResMut<State<DayState>>,
+    mut input_state: ResMut<State<InputState>>,
+) {
+    for event in events.iter() {
+        if let FileDragAndDrop::DroppedFile { path_buf, .. } = event {
+            match fs::read_to_string(path_buf) {
+                Ok(input) => {
+                    input_state.set(InputState(input)).ok();
+                    day_state.set(DayState::Edit).ok();
+                }
+                Err(err) => warn!("could not read {}: {}", path_buf.display(), err),
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn file_input_system(
+    mut day_state: ResMut<State<DayState>>,
+    mut input_state: ResMut<State<InputState>>,
+) {
+    if let Some(input) = web_file::take() {
+        input_state.set(InputState(input)).ok();
+        day_state.set(DayState::Edit).ok();
+    }
+}
+
+fn day_edit_setup(
+    day_select_state: Res<State<DaySelectState>>,
+    input_state: Res<State<InputState>>,
+    day_params: Res<DayParameters>,
+    solvers: Res<Solvers>,
+    mut editor: ResMut<InputEditor>,
+) {
+    let solver = solvers.get(day_select_state.current().0).unwrap();
+    editor.open(input_state.current().0.clone(), solver, &day_params.0);
+}
+
+fn input_editor_system(
+    mut egui_context: ResMut<EguiContext>,
+    day_select_state: Res<State<DaySelectState>>,
+    solvers: Res<Solvers>,
+    mut editor: ResMut<InputEditor>,
+    mut day_state: ResMut<State<DayState>>,
+    mut input_state: ResMut<State<InputState>>,
+    mut day_params: ResMut<DayParameters>,
+) {
+    let day = day_select_state.current().0;
+    let solver = solvers.get(day).unwrap();
+    let title = format!("Day {} input", day);
+    match editor.show(egui_context.ctx_mut(), &title, solver) {
+        Some(EditorAction::Solve) => {
+            input_state.set(InputState(editor.text.clone())).ok();
+            day_params.0 = editor.parameters(solver);
+            day_state.set(DayState::Solving).ok();
+        }
+        Some(EditorAction::Back) => {
+            day_state.set(DayState::Input).ok();
+        }
+        None => {}
+    }
+}
+
+fn day_solving_setup(
     mut commands: Commands,
     parent: Query<Entity, With<OnDayScreen>>,
     asset_server: Res<AssetServer>,
     day_select_state: Res<State<DaySelectState>>,
+    input_state: Res<State<InputState>>,
+    day_params: Res<DayParameters>,
+    solvers: Res<Solvers>,
+) {
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let day = day_select_state.current().0;
+    let input = input_state.current().0.clone();
+    let parent = parent.iter().next().unwrap();
+    let task = SolveTask::spawn(Arc::clone(&solvers), day, input, day_params.0.clone());
+
+    commands.entity(parent).with_children(|parent| {
+        parent
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        size: Size {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(90.0),
+                        },
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    ..default()
+                },
+                OnDaySolvingScreen,
+                task,
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        format!("{} Solving…", SPINNER[0]),
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 50.0,
+                            color: Color::rgb(1.0, 1.0, 1.0),
+                        },
+                    )
+                    .with_style(Style {
+                        margin: UiRect::all(Val::Px(50.0)),
+                        ..default()
+                    }),
+                    SolvingLabel,
+                ));
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            background_color: BUTTON_BACKGROUND.into(),
+                            ..default()
+                        },
+                        ButtonAction::Cancel,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(
+                            TextBundle::from_section(
+                                "Cancel",
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 40.0,
+                                    color: Color::rgb(1.0, 1.0, 1.0),
+                                },
+                            )
+                            .with_style(Style {
+                                margin: UiRect::all(Val::Px(20.0)),
+                                ..default()
+                            }),
+                        );
+                    });
+            });
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn solving_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tasks: Query<(Entity, &mut SolveTask)>,
+    mut labels: Query<&mut Text, With<SolvingLabel>>,
+    mut solution: ResMut<Solution>,
+    mut store: ResMut<Store>,
+    mut day_state: ResMut<State<DayState>>,
+    day_select_state: Res<State<DaySelectState>>,
+    input_state: Res<State<InputState>>,
+    day_params: Res<DayParameters>,
+) {
+    let frame = (time.elapsed_seconds() * 8.0) as usize % SPINNER.len();
+    for mut text in &mut labels {
+        text.sections[0].value = format!("{} Solving…", SPINNER[frame]);
+    }
+    for (entity, mut task) in &mut tasks {
+        if let Some(result) = task.poll() {
+            commands.entity(entity).remove::<SolveTask>();
+            let input = input_state.current().0.clone();
+            let record = result
+                .as_ref()
+                .ok()
+                .and_then(|timed| DayRecord::new(input, &day_params.0, timed));
+            if let Some(record) = record {
+                store.insert(day_select_state.current().0, record);
+                store.save();
+            }
+            solution.0 = Some(result);
+            day_state.set(DayState::Show).ok();
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn day_show_setup(
+    mut commands: Commands,
+    parent: Query<Entity, With<OnDayScreen>>,
+    asset_server: Res<AssetServer>,
     mut part1_state: ResMut<State<Part1State>>,
     mut part2_state: ResMut<State<Part2State>>,
+    solution: Res<Solution>,
+    mut report: ResMut<Report>,
+    day_select_state: Res<State<DaySelectState>>,
     input_state: Res<State<InputState>>,
+    day_params: Res<DayParameters>,
+    solvers: Res<Solvers>,
+    store: Res<Store>,
 ) {
     let font: Handle<Font> = asset_server.load("fonts/FiraMono-Medium.ttf");
-    let day = day_select_state.current().0;
-    let input = &input_state.current().0;
     let parent = parent.iter().next().unwrap();
-    let (part1, part2) = match day {
-        1 => day01::solve(input),
-        2 => day02::solve(input),
-        3 => day03::solve(input),
-        4 => day04::solve(input),
-        5 => day05::solve(input),
-        6 => day06::solve(input),
-        7 => day07::solve(input),
-        8 => day08::solve(input),
-        9 => day09::solve(input),
-        10 => day10::solve(input),
-        11 => day11::solve(input),
-        12 => day12::solve(input),
-        13 => day13::solve(input),
-        14 => day14::solve(input),
-        15 => day15::solve(input, 2000000),
-        16 => day16::solve(input),
-        17 => day17::solve(input),
-        18 => day18::solve(input),
-        19 => day19::solve(input),
-        20 => day20::solve(input),
-        21 => day21::solve(input),
-        22 => day22::solve(input, 50),
-        23 => day23::solve(input),
-        24 => day24::solve(input),
-        25 => day25::solve(input),
-        _ => unimplemented!(),
-    };
+    let day = day_select_state.current().0;
+    let result = solution.0.clone().unwrap();
+    let solver = solvers.get(day).unwrap();
+    report.0 = Some(SolveReport::new(solver, &day_params.0, &result));
 
-    part1_state.set(Part1State(part1.clone())).ok();
-    part2_state.set(Part2State(part2.clone())).ok();
+    let answer = |part| {
+        result
+            .as_ref()
+            .ok()
+            .and_then(|timed| timed.answer(part))
+            .unwrap_or_default()
+            .to_owned()
+    };
+    part1_state.set(Part1State(answer(1))).ok();
+    part2_state.set(Part2State(answer(2))).ok();
+    let mut beam = None;
     commands.entity(parent).with_children(|parent| {
         parent
             .spawn((
@@ -304,19 +568,130 @@ fn day_show_setup(
                 OnDayShowScreen,
             ))
             .with_children(|parent| {
-                build_part_button(parent, &font, 1, &part1, ButtonAction::CopyPart1);
-                build_part_button(parent, &font, 2, &part2, ButtonAction::CopyPart2);
+                let mut buttons = vec![
+                    ("Re-run".to_owned(), ButtonAction::Rerun),
+                    ("Edit input".to_owned(), ButtonAction::EditInput),
+                    ("Copy JSON".to_owned(), ButtonAction::CopyReport),
+                ];
+                #[cfg(target_arch = "wasm32")]
+                buttons.push(("Share link".to_owned(), ButtonAction::ShareLink));
+                match &result {
+                    Ok(timed) => {
+                        let actions = [ButtonAction::CopyPart1, ButtonAction::CopyPart2];
+                        let accepts = [ButtonAction::AcceptPart1, ButtonAction::AcceptPart2];
+                        for ((part, (result, elapsed)), action) in
+                            (1..).zip(&timed.parts).zip(actions)
+                        {
+                            // A failed part shows its error, the other part
+                            // still shows its answer.
+                            let answer = match result {
+                                Ok(answer) => answer,
+                                Err(err) => {
+                                    build_error_label(parent, &font, err);
+                                    continue;
+                                }
+                            };
+                            let timing = format!(
+                                "parse {} | part {}",
+                                format_duration(timed.parse),
+                                format_duration(*elapsed)
+                            );
+                            let verdict = store.verify(day, part, answer);
+                            // Day 10 draws its part 2 answer on a screen.
+                            let crt = match (day, part) {
+                                (10, 2) => Crt::new(&input_state.current().0).ok(),
+                                _ => None,
+                            };
+                            beam = beam.or(build_part_button(
+                                parent, &font, part, answer, &timing, &verdict, action, crt,
+                            ));
+                        }
+                        for ((part, (result, _)), accept) in (1..).zip(&timed.parts).zip(accepts) {
+                            if matches!(result, Ok(answer) if store.verify(day, part, answer) != Verdict::Correct) {
+                                buttons.push((format!("Accept part {}", part), accept));
+                            }
+                        }
+                        if Scene::of(day).is_some() {
+                            buttons.push(("Visualize".to_owned(), ButtonAction::Visualize));
+                        }
+                    }
+                    Err(err) => build_error_label(parent, &font, err),
+                }
+                build_show_actions(parent, &font, buttons);
             });
     });
+    if let Some(beam) = beam {
+        commands.insert_resource(beam);
+    }
+}
+
+fn build_show_actions(
+    parent: &mut ChildBuilder,
+    font: &Handle<Font>,
+    buttons: Vec<(String, ButtonAction)>,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                margin: UiRect::all(Val::Px(20.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for (label, action) in buttons {
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                margin: UiRect::horizontal(Val::Px(10.0)),
+                                ..default()
+                            },
+                            background_color: BUTTON_BACKGROUND.into(),
+                            ..default()
+                        },
+                        action,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(
+                            TextBundle::from_section(
+                                label,
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 40.0,
+                                    color: Color::rgb(1.0, 1.0, 1.0),
+                                },
+                            )
+                            .with_style(Style {
+                                margin: UiRect::all(Val::Px(20.0)),
+                                ..default()
+                            }),
+                        );
+                    });
+            }
+        });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_part_button(
     parent: &mut ChildBuilder,
     font: &Handle<Font>,
     part: usize,
     res: &str,
+    timing: &str,
+    verdict: &Verdict,
     action: ButtonAction,
-) {
+    crt: Option<Crt>,
+) -> Option<Beam> {
+    let mut beam = None;
+    let (color, note) = match verdict {
+        Verdict::Unknown => (Color::rgb(1.0, 1.0, 1.0), String::new()),
+        Verdict::Correct => (CORRECT_TEXT, "matches your accepted answer".to_owned()),
+        Verdict::Wrong(accepted) => (WRONG_TEXT, format!("your accepted answer is {}", accepted)),
+    };
     parent
         .spawn((
             ButtonBundle {
@@ -350,9 +725,79 @@ fn build_part_button(
                     ..default()
                 }),
             );
+            match crt {
+                Some(crt) => beam = Some(crt::build_screen(parent, font, crt, res.lines().count())),
+                None => {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            res.to_string(),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 50.0,
+                                color,
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::all(Val::Px(50.0)),
+                            ..default()
+                        }),
+                    );
+                }
+            }
             parent.spawn(
                 TextBundle::from_section(
-                    res.to_string(),
+                    timing,
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 25.0,
+                        color: Color::rgb(0.6, 0.6, 0.6),
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                }),
+            );
+            if !note.is_empty() {
+                parent.spawn(
+                    TextBundle::from_section(
+                        note,
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 25.0,
+                            color,
+                        },
+                    )
+                    .with_style(Style {
+                        margin: UiRect::all(Val::Px(20.0)),
+                        ..default()
+                    }),
+                );
+            }
+        });
+    beam
+}
+
+fn build_error_label(parent: &mut ChildBuilder, font: &Handle<Font>, err: &SolveError) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                margin: UiRect::all(Val::Auto),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                size: Size {
+                    width: Val::Percent(95.0),
+                    height: Val::Auto,
+                },
+                ..default()
+            },
+            background_color: ERROR_BACKGROUND.into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    "Invalid input",
                     TextStyle {
                         font: font.clone(),
                         font_size: 50.0,
@@ -364,57 +809,202 @@ fn build_part_button(
                     ..default()
                 }),
             );
+            parent.spawn(
+                TextBundle::from_section(
+                    err.to_string(),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 30.0,
+                        color: Color::rgb(1.0, 1.0, 1.0),
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::all(Val::Px(50.0)),
+                    ..default()
+                }),
+            );
         });
 }
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn exit_system(
     mut interaction_query: Query<
         (&Interaction, &mut BackgroundColor, &ButtonAction),
         (Changed<Interaction>, With<Button>),
     >,
+    keys: Res<Input<KeyCode>>,
+    mut egui_context: ResMut<EguiContext>,
     mut day_state: ResMut<State<DayState>>,
     mut game_state: ResMut<State<GameState>>,
     mut input_state: ResMut<State<InputState>>,
     mut egui_clipboard: ResMut<EguiClipboard>,
+    mut store: ResMut<Store>,
+    report: Res<Report>,
+    day_select_state: Res<State<DaySelectState>>,
     part1_state: Res<State<Part1State>>,
     part2_state: Res<State<Part2State>>,
 ) {
+    let day = day_select_state.current().0;
+    let mut actions = vec![];
     for (interaction, mut color, button_action) in &mut interaction_query {
         if *interaction == Interaction::Clicked {
-            match button_action {
-                ButtonAction::Exit => {
-                    day_state.set(DayState::Disabled).unwrap();
-                    game_state.set(GameState::Menu).unwrap();
-                }
-                ButtonAction::Paste => {
-                    #[cfg(target_arch = "wasm32")]
-                    let input = read_clipboard();
-                    #[cfg(not(target_arch = "wasm32"))]
-                    let input = read_clipboard(&egui_clipboard);
+            actions.push(*button_action);
+        }
+        *color = match *interaction {
+            Interaction::Clicked => PRESSED_BUTTON.into(),
+            Interaction::Hovered => HOVER_BUTTON.into(),
+            Interaction::None => BUTTON_BACKGROUND.into(),
+        }
+    }
+    if !egui_context.ctx_mut().wants_keyboard_input() {
+        actions.extend(key_actions(&keys, day_state.current()));
+    }
 
-                    input_state.set(InputState(input)).ok();
-                    day_state.set(DayState::Show).unwrap();
-                }
-                ButtonAction::CopyPart1 => {
-                    #[cfg(target_arch = "wasm32")]
-                    set_clipboard(&part1_state.current().0);
-                    #[cfg(not(target_arch = "wasm32"))]
-                    set_clipboard(&part1_state.current().0, &mut egui_clipboard);
-                }
-                ButtonAction::CopyPart2 => {
+    for action in actions {
+        match action {
+            ButtonAction::Exit => {
+                day_state.set(DayState::Disabled).ok();
+                game_state.set(GameState::Menu).ok();
+            }
+            ButtonAction::Paste => {
+                #[cfg(target_arch = "wasm32")]
+                let input = read_clipboard();
+                #[cfg(not(target_arch = "wasm32"))]
+                let input = read_clipboard(&egui_clipboard);
+
+                input_state.set(InputState(input)).ok();
+                day_state.set(DayState::Edit).ok();
+            }
+            ButtonAction::Cancel => {
+                day_state.set(DayState::Input).ok();
+            }
+            ButtonAction::Rerun => {
+                day_state.set(DayState::Solving).ok();
+            }
+            ButtonAction::EditInput => {
+                day_state.set(DayState::Edit).ok();
+            }
+            ButtonAction::CopyPart1 => {
+                #[cfg(target_arch = "wasm32")]
+                set_clipboard(&part1_state.current().0);
+                #[cfg(not(target_arch = "wasm32"))]
+                set_clipboard(&part1_state.current().0, &mut egui_clipboard);
+            }
+            ButtonAction::CopyPart2 => {
+                #[cfg(target_arch = "wasm32")]
+                set_clipboard(&part2_state.current().0);
+                #[cfg(not(target_arch = "wasm32"))]
+                set_clipboard(&part2_state.current().0, &mut egui_clipboard);
+            }
+            ButtonAction::CopyAnswers => {
+                let answers = format!("{}\n{}", part1_state.current().0, part2_state.current().0);
+                #[cfg(target_arch = "wasm32")]
+                set_clipboard(&answers);
+                #[cfg(not(target_arch = "wasm32"))]
+                set_clipboard(&answers, &mut egui_clipboard);
+            }
+            ButtonAction::CopyReport => {
+                let json = report.0.as_ref().map(serde_json::to_string);
+                if let Some(Ok(json)) = json {
                     #[cfg(target_arch = "wasm32")]
-                    set_clipboard(&part2_state.current().0);
+                    set_clipboard(&json);
                     #[cfg(not(target_arch = "wasm32"))]
-                    set_clipboard(&part2_state.current().0, &mut egui_clipboard);
+                    set_clipboard(&json, &mut egui_clipboard);
                 }
             }
+            #[cfg(target_arch = "wasm32")]
+            ButtonAction::ShareLink => {
+                set_clipboard(&web_route::link(day, &input_state.current().0));
+            }
+            ButtonAction::AcceptPart1 => {
+                accept_answer(&mut store, day, 1, &part1_state.current().0);
+                day_state.restart().ok();
+            }
+            ButtonAction::AcceptPart2 => {
+                accept_answer(&mut store, day, 2, &part2_state.current().0);
+                day_state.restart().ok();
+            }
+            ButtonAction::Visualize if Scene::of(day).is_some() => {
+                day_state.set(DayState::Disabled).ok();
+                game_state.set(GameState::Visualize).ok();
+            }
+            ButtonAction::Visualize => {}
         }
-        *color = match *interaction {
-            Interaction::Clicked => PRESSED_BUTTON.into(),
-            Interaction::Hovered => HOVER_BUTTON.into(),
-            Interaction::None => BUTTON_BACKGROUND.into(),
+    }
+}
+
+// Escape leaves the day from any screen, Ctrl+V pastes on the input screen and
+// C, 1, 2 and J copy both answers, part 1, part 2 or the JSON report on the
+// show screen, where V without Ctrl opens the visualization.
+fn key_actions(keys: &Input<KeyCode>, day_state: &DayState) -> Vec<ButtonAction> {
+    let mut actions = vec![];
+    if keys.just_pressed(KeyCode::Escape) {
+        actions.push(ButtonAction::Exit);
+    }
+    let ctrl = keys.any_pressed([
+        KeyCode::LControl,
+        KeyCode::RControl,
+        KeyCode::LWin,
+        KeyCode::RWin,
+    ]);
+    match day_state {
+        DayState::Input if ctrl && keys.just_pressed(KeyCode::V) => {
+            actions.push(ButtonAction::Paste);
         }
+        DayState::Show => {
+            if keys.just_pressed(KeyCode::C) {
+                actions.push(ButtonAction::CopyAnswers);
+            }
+            if keys.any_just_pressed([KeyCode::Key1, KeyCode::Numpad1]) {
+                actions.push(ButtonAction::CopyPart1);
+            }
+            if keys.any_just_pressed([KeyCode::Key2, KeyCode::Numpad2]) {
+                actions.push(ButtonAction::CopyPart2);
+            }
+            if keys.just_pressed(KeyCode::J) {
+                actions.push(ButtonAction::CopyReport);
+            }
+            if !ctrl && keys.just_pressed(KeyCode::V) {
+                actions.push(ButtonAction::Visualize);
+            }
+        }
+        _ => {}
+    }
+    actions
+}
+
+// The show screen is then rebuilt from the same result so the verdicts are
+// redrawn, also when a failed part kept the run out of the store.
+fn accept_answer(store: &mut Store, day: usize, part: usize, answer: &str) {
+    store.accept(day, part, answer.to_owned());
+    store.save();
+}
+
+#[cfg(target_arch = "wasm32")]
+fn open_route(
+    mut game_state: ResMut<State<GameState>>,
+    mut day_select_state: ResMut<State<DaySelectState>>,
+    mut shared_input: ResMut<SharedInput>,
+    solvers: Res<Solvers>,
+) {
+    let route = web_route::read();
+    if let Some(day) = route.day.filter(|day| solvers.contains(*day)) {
+        day_select_state.set(DaySelectState(day)).ok();
+        game_state.set(GameState::Day).ok();
+        shared_input.0 = route.input;
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn update_route(
+    game_state: Res<State<GameState>>,
+    day_select_state: Res<State<DaySelectState>>,
+    mut written: Local<Option<Option<usize>>>,
+) {
+    let day = (*game_state.current() == GameState::Day).then(|| day_select_state.current().0);
+    if *written != Some(day) {
+        web_route::write(day);
+        *written = Some(day);
     }
 }
 
@@ -434,7 +1024,7 @@ fn read_clipboard(clipboard: &ResMut<EguiClipboard>) -> String {
 }
 
 #[cfg(target_arch = "wasm32")]
-fn set_clipboard(value: &str) {
+pub fn set_clipboard(value: &str) {
     let window = web_sys::window().unwrap();
     let document = window.document().unwrap();
     let val = document.get_element_by_id("aoc2022-input").unwrap();
@@ -443,6 +1033,6 @@ fn set_clipboard(value: &str) {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn set_clipboard(value: &str, clipboard: &mut ResMut<EguiClipboard>) {
+pub fn set_clipboard(value: &str, clipboard: &mut ResMut<EguiClipboard>) {
     clipboard.set_contents(value);
 }
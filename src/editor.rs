@@ -0,0 +1,183 @@
+use aoc_2022_solutions::{Parameters, Solver};
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+const FONT_SIZE: f32 = 14.0;
+
+pub enum EditorAction {
+    Solve,
+    Back,
+}
+
+#[derive(Resource, Default)]
+pub struct InputEditor {
+    pub text: String,
+    values: Vec<Option<i64>>,
+    issues: Vec<String>,
+}
+
+impl InputEditor {
+    pub fn open(&mut self, text: String, solver: &dyn Solver, params: &Parameters) {
+        self.text = text;
+        self.values = solver
+            .params()
+            .iter()
+            .map(|param| params.given(param))
+            .collect();
+        self.issues = issues(solver, &self.text);
+    }
+
+    pub fn parameters(&self, solver: &dyn Solver) -> Parameters {
+        let mut params = Parameters::default();
+        for (param, value) in solver.params().iter().zip(&self.values) {
+            if let Some(value) = value {
+                params.set(param.name, *value);
+            }
+        }
+        params
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        title: &str,
+        solver: &dyn Solver,
+    ) -> Option<EditorAction> {
+        let mut action = None;
+        let mut changed = false;
+        let size = ctx.available_rect().size() * egui::vec2(0.8, 0.6);
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .fixed_size(size)
+            .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -20.0])
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "{} lines, {} characters",
+                    self.text.lines().count(),
+                    self.text.len()
+                ));
+                if self.issues.is_empty() {
+                    ui.colored_label(egui::Color32::LIGHT_GREEN, "No issues found");
+                }
+                for issue in &self.issues {
+                    ui.colored_label(egui::Color32::LIGHT_RED, issue);
+                }
+                if !self.values.is_empty() {
+                    ui.horizontal(|ui| {
+                        for (param, value) in solver.params().iter().zip(&mut self.values) {
+                            ui.label(param.name).on_hover_text(param.description);
+                            let mut auto = value.is_none();
+                            if ui
+                                .checkbox(&mut auto, "auto")
+                                .on_hover_text("Detect from the input")
+                                .changed()
+                            {
+                                *value = (!auto).then_some(param.default);
+                            }
+                            if let Some(value) = value {
+                                ui.add(egui::DragValue::new(value));
+                            }
+                        }
+                    });
+                }
+
+                egui::ScrollArea::both()
+                    .max_height(size.y - 100.0)
+                    .show(ui, |ui| {
+                        ui.horizontal_top(|ui| {
+                            ui.vertical(|ui| {
+                                ui.add_space(2.0);
+                                ui.label(
+                                    egui::RichText::new(line_numbers(&self.text))
+                                        .font(egui::FontId::monospace(FONT_SIZE))
+                                        .weak(),
+                                );
+                            });
+                            let mut layouter = |ui: &egui::Ui, text: &str, _: f32| {
+                                ui.fonts().layout_no_wrap(
+                                    text.to_owned(),
+                                    egui::FontId::monospace(FONT_SIZE),
+                                    ui.visuals().text_color(),
+                                )
+                            };
+                            changed = ui
+                                .add(
+                                    egui::TextEdit::multiline(&mut self.text)
+                                        .code_editor()
+                                        .desired_width(f32::INFINITY)
+                                        .layouter(&mut layouter),
+                                )
+                                .changed();
+                        });
+                    });
+
+                ui.horizontal(|ui| {
+                    if ui.button("Solve").clicked() {
+                        action = Some(EditorAction::Solve);
+                    }
+                    if ui.button("Back").clicked() {
+                        action = Some(EditorAction::Back);
+                    }
+                });
+            });
+        if changed {
+            self.issues = issues(solver, &self.text);
+        }
+        action
+    }
+}
+
+fn line_numbers(text: &str) -> String {
+    let rows = text.split('\n').count();
+    (1..=rows)
+        .map(|row| row.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn issues(solver: &dyn Solver, input: &str) -> Vec<String> {
+    let mut issues = vec![];
+    if input.trim().is_empty() {
+        issues.push("Input is empty".to_owned());
+        return issues;
+    }
+    if input.contains('\r') {
+        issues.push("Input has Windows (CRLF) line endings".to_owned());
+    }
+    let trailing = input
+        .lines()
+        .filter(|line| line.ends_with([' ', '\t']))
+        .count();
+    if trailing > 0 {
+        issues.push(format!("{} lines end with whitespace", trailing));
+    }
+    if let Err(err) = solver.parse(input) {
+        issues.push(err.to_string());
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2022_solutions::Registry;
+
+    #[test]
+    fn input_issues() {
+        let registry = Registry::default();
+        let solver = registry.get(1).unwrap();
+
+        assert_eq!(line_numbers("1\n2\n"), "1\n2\n3");
+        assert!(issues(solver, "1000\n2000\n\n3000\n").is_empty());
+        assert_eq!(issues(solver, " \n"), ["Input is empty"]);
+        assert_eq!(
+            issues(solver, "1000 \r\n2000\r\nx\r\n"),
+            [
+                "Input has Windows (CRLF) line endings",
+                "1 lines end with whitespace",
+                "line 1, column 1: invalid value \"1000 \": invalid digit found in string",
+            ]
+        );
+    }
+}
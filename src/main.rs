@@ -1,8 +1,22 @@
 use bevy::prelude::*;
 use bevy_egui::EguiPlugin;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod cli;
+mod crt;
+mod dashboard;
 mod days;
+mod editor;
 mod menu;
+#[cfg(any(target_arch = "wasm32", test))]
+mod share;
+mod solving;
+mod store;
+mod visualize;
+#[cfg(target_arch = "wasm32")]
+mod web_file;
+#[cfg(target_arch = "wasm32")]
+mod web_route;
 
 const IDLE_BUTTON: Color = Color::rgb(0.0549, 0.1765, 0.2549);
 const PRESSED_BUTTON: Color = Color::rgb(0.5961, 0.1451, 0.1176);
@@ -12,12 +26,19 @@ const HOVER_BUTTON: Color = Color::rgb(0.0941, 0.2588, 0.3529);
 enum GameState {
     Menu,
     Day,
+    Dashboard,
+    Visualize,
 }
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
 struct DaySelectState(usize);
 
 fn main() {
+    #[cfg(not(target_arch = "wasm32"))]
+    if std::env::args().nth(1).as_deref() == Some("--headless") {
+        std::process::exit(cli::run(std::env::args().skip(2)));
+    }
+
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             window: WindowDescriptor {
@@ -33,6 +54,8 @@ fn main() {
         .add_state(DaySelectState(0))
         .add_plugin(menu::MenuPlugin)
         .add_plugin(days::DaysPlugin)
+        .add_plugin(dashboard::DashboardPlugin)
+        .add_plugin(visualize::VisualizePlugin)
         .run();
 }
 
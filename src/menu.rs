@@ -1,6 +1,6 @@
 use crate::{
-    days::MAX_DAY, despawn_screen, DaySelectState, GameState, HOVER_BUTTON, IDLE_BUTTON,
-    PRESSED_BUTTON,
+    days::Solvers, despawn_screen, store::Store, DaySelectState, GameState, HOVER_BUTTON,
+    IDLE_BUTTON, PRESSED_BUTTON,
 };
 use bevy::{prelude::*, ui::FocusPolicy};
 
@@ -8,11 +8,18 @@ pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_enter(GameState::Menu).with_system(menu_setup))
+        app.init_resource::<MenuFocus>()
+            .add_system_set(SystemSet::on_enter(GameState::Menu).with_system(menu_setup))
             .add_system_set(
                 SystemSet::on_exit(GameState::Menu).with_system(despawn_screen::<OnMenuScreen>),
             )
-            .add_system_set(SystemSet::on_update(GameState::Menu).with_system(menu_action));
+            .add_system_set(
+                SystemSet::on_update(GameState::Menu)
+                    .with_system(menu_action)
+                    .with_system(menu_keyboard)
+                    .with_system(dashboard_action)
+                    .with_system(menu_focus_highlight),
+            );
     }
 }
 
@@ -22,7 +29,45 @@ struct OnMenuScreen;
 #[derive(Component)]
 struct DayAction(usize);
 
-fn menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+#[derive(Component)]
+struct DashboardButton;
+
+const MISSING_STAR: Color = Color::rgb(0.3, 0.3, 0.3);
+// Day buttons per row, both for sizing them in the wrapping grid and for
+// moving the focus up and down.
+const COLUMNS: usize = 6;
+const DIGIT_TIMEOUT: f32 = 1.0;
+
+#[derive(Resource)]
+struct MenuFocus {
+    day: usize,
+    // The number typed so far and when, so "1" then "2" focuses day 12.
+    typed: Option<(usize, f32)>,
+}
+
+impl Default for MenuFocus {
+    fn default() -> Self {
+        Self {
+            day: 1,
+            typed: None,
+        }
+    }
+}
+
+fn menu_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    solvers: Res<Solvers>,
+    store: Res<Store>,
+) {
+    // Every day up to the last solver gets a button, the ones in between
+    // without a solver a snowflake.
+    let days = solvers.max_day();
+    let rows = days.div_ceil(COLUMNS).max(1);
+    let button_size = Size::new(
+        Val::Percent(90.0 / COLUMNS as f32),
+        Val::Percent(90.0 / rows as f32),
+    );
     commands
         .spawn((
             NodeBundle {
@@ -63,6 +108,36 @@ fn menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                             ..default()
                         }),
                     );
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    margin: UiRect::all(Val::Px(5.0)),
+                                    ..default()
+                                },
+                                background_color: IDLE_BUTTON.into(),
+                                ..default()
+                            },
+                            DashboardButton,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(
+                                TextBundle::from_section(
+                                    "Dashboard",
+                                    TextStyle {
+                                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                        font_size: 20.0,
+                                        color: Color::WHITE,
+                                    },
+                                )
+                                .with_style(Style {
+                                    margin: UiRect::all(Val::Px(5.0)),
+                                    ..default()
+                                }),
+                            );
+                        });
                 });
             parent
                 .spawn(NodeBundle {
@@ -76,12 +151,12 @@ fn menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 })
                 .with_children(|parent| {
-                    for i in 0..25 {
+                    for i in 0..days {
                         parent
                             .spawn((
                                 ButtonBundle {
                                     style: Style {
-                                        size: Size::new(Val::Percent(15.0), Val::Percent(18.0)),
+                                        size: button_size,
                                         align_items: AlignItems::Center,
                                         justify_content: JustifyContent::Center,
                                         ..default()
@@ -92,21 +167,38 @@ fn menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                                 DayAction(i + 1),
                             ))
                             .with_children(|parent| {
-                                let image_name = if i < MAX_DAY {
-                                    "img/star.png"
+                                if solvers.contains(i + 1) {
+                                    let stars = store.stars(i + 1);
+                                    let image: Handle<Image> = asset_server.load("img/star.png");
+                                    for star in 0..2 {
+                                        let tint = if star < stars {
+                                            Color::WHITE
+                                        } else {
+                                            MISSING_STAR
+                                        };
+                                        parent.spawn(ImageBundle {
+                                            style: Style {
+                                                size: Size::new(Val::Percent(35.0), Val::Auto),
+                                                ..default()
+                                            },
+                                            image: UiImage::from(image.clone()),
+                                            background_color: tint.into(),
+                                            focus_policy: FocusPolicy::Pass,
+                                            ..default()
+                                        });
+                                    }
                                 } else {
-                                    "img/snowflake.png"
-                                };
-                                let snowflake = asset_server.load(image_name);
-                                parent.spawn(ImageBundle {
-                                    style: Style {
-                                        size: Size::new(Val::Percent(50.0), Val::Auto),
+                                    let snowflake = asset_server.load("img/snowflake.png");
+                                    parent.spawn(ImageBundle {
+                                        style: Style {
+                                            size: Size::new(Val::Percent(50.0), Val::Auto),
+                                            ..default()
+                                        },
+                                        image: UiImage::from(snowflake),
+                                        focus_policy: FocusPolicy::Pass,
                                         ..default()
-                                    },
-                                    image: UiImage::from(snowflake),
-                                    focus_policy: FocusPolicy::Pass,
-                                    ..default()
-                                });
+                                    });
+                                }
                                 parent.spawn(
                                     TextBundle::from_section(
                                         format!("{}", i + 1),
@@ -136,24 +228,148 @@ fn menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
 
 #[allow(clippy::type_complexity)]
 fn menu_action(
+    interaction_query: Query<(&Interaction, &DayAction), (Changed<Interaction>, With<Button>)>,
+    mut focus: ResMut<MenuFocus>,
+    mut game_state: ResMut<State<GameState>>,
+    mut day_select_state: ResMut<State<DaySelectState>>,
+    solvers: Res<Solvers>,
+) {
+    for (interaction, day_action) in &interaction_query {
+        match *interaction {
+            Interaction::Clicked => open_day(
+                day_action.0,
+                &solvers,
+                &mut game_state,
+                &mut day_select_state,
+            ),
+            Interaction::Hovered => focus.day = day_action.0,
+            Interaction::None => {}
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn dashboard_action(
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &DayAction),
-        (Changed<Interaction>, With<Button>),
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<DashboardButton>),
     >,
+    keys: Res<Input<KeyCode>>,
+    mut game_state: ResMut<State<GameState>>,
+) {
+    let mut open = keys.just_pressed(KeyCode::D);
+    for (interaction, mut color) in &mut interaction_query {
+        open |= *interaction == Interaction::Clicked;
+        *color = match *interaction {
+            Interaction::Clicked => PRESSED_BUTTON.into(),
+            Interaction::Hovered => HOVER_BUTTON.into(),
+            Interaction::None => IDLE_BUTTON.into(),
+        }
+    }
+    if open {
+        game_state.set(GameState::Dashboard).ok();
+    }
+}
+
+fn menu_keyboard(
+    keys: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    mut focus: ResMut<MenuFocus>,
     mut game_state: ResMut<State<GameState>>,
     mut day_select_state: ResMut<State<DaySelectState>>,
+    solvers: Res<Solvers>,
 ) {
-    for (interaction, mut color, day_action) in &mut interaction_query {
-        if *interaction == Interaction::Clicked {
-            if let 1..=MAX_DAY = day_action.0 {
-                game_state.set(GameState::Day).unwrap();
-                day_select_state.set(DaySelectState(day_action.0)).ok();
+    let days = solvers.max_day();
+    for key in keys.get_just_pressed() {
+        if let Some(digit) = digit(*key) {
+            let now = time.elapsed_seconds();
+            let typed = match focus.typed {
+                Some((typed, at)) if now - at < DIGIT_TIMEOUT && typed * 10 + digit <= days => {
+                    typed * 10 + digit
+                }
+                _ => digit,
+            };
+            if (1..=days).contains(&typed) {
+                focus.day = typed;
             }
+            focus.typed = Some((typed, now));
+        } else if matches!(key, KeyCode::Return | KeyCode::NumpadEnter) {
+            open_day(focus.day, &solvers, &mut game_state, &mut day_select_state);
+        } else {
+            focus.day = move_focus(focus.day, *key, days);
         }
+    }
+}
+
+fn menu_focus_highlight(
+    focus: Res<MenuFocus>,
+    mut buttons: Query<(&Interaction, &mut BackgroundColor, &DayAction), With<Button>>,
+) {
+    for (interaction, mut color, day_action) in &mut buttons {
         *color = match *interaction {
             Interaction::Clicked => PRESSED_BUTTON.into(),
-            Interaction::Hovered => HOVER_BUTTON.into(),
-            Interaction::None => IDLE_BUTTON.into(),
+            _ if day_action.0 == focus.day => HOVER_BUTTON.into(),
+            _ => IDLE_BUTTON.into(),
         }
     }
 }
+
+fn open_day(
+    day: usize,
+    solvers: &Solvers,
+    game_state: &mut State<GameState>,
+    day_select_state: &mut State<DaySelectState>,
+) {
+    if solvers.contains(day) {
+        game_state.set(GameState::Day).ok();
+        day_select_state.set(DaySelectState(day)).ok();
+    }
+}
+
+fn move_focus(day: usize, key: KeyCode, days: usize) -> usize {
+    let target = match key {
+        KeyCode::Left => day.checked_sub(1),
+        KeyCode::Right => Some(day + 1),
+        KeyCode::Up => day.checked_sub(COLUMNS),
+        KeyCode::Down => Some(day + COLUMNS),
+        _ => None,
+    };
+    target.filter(|day| (1..=days).contains(day)).unwrap_or(day)
+}
+
+fn digit(key: KeyCode) -> Option<usize> {
+    let digits = [
+        [KeyCode::Key0, KeyCode::Numpad0],
+        [KeyCode::Key1, KeyCode::Numpad1],
+        [KeyCode::Key2, KeyCode::Numpad2],
+        [KeyCode::Key3, KeyCode::Numpad3],
+        [KeyCode::Key4, KeyCode::Numpad4],
+        [KeyCode::Key5, KeyCode::Numpad5],
+        [KeyCode::Key6, KeyCode::Numpad6],
+        [KeyCode::Key7, KeyCode::Numpad7],
+        [KeyCode::Key8, KeyCode::Numpad8],
+        [KeyCode::Key9, KeyCode::Numpad9],
+    ];
+    digits.iter().position(|keys| keys.contains(&key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyboard_focus() {
+        assert_eq!(move_focus(1, KeyCode::Left, 25), 1);
+        assert_eq!(move_focus(1, KeyCode::Right, 25), 2);
+        assert_eq!(move_focus(8, KeyCode::Up, 25), 2);
+        assert_eq!(move_focus(3, KeyCode::Up, 25), 3);
+        assert_eq!(move_focus(19, KeyCode::Down, 25), 25);
+        assert_eq!(move_focus(20, KeyCode::Down, 25), 20);
+        assert_eq!(move_focus(25, KeyCode::Right, 25), 25);
+        assert_eq!(move_focus(6, KeyCode::Down, 12), 12);
+        assert_eq!(move_focus(7, KeyCode::Down, 12), 7);
+        assert_eq!(digit(KeyCode::Key7), Some(7));
+        assert_eq!(digit(KeyCode::Numpad0), Some(0));
+        assert_eq!(digit(KeyCode::A), None);
+    }
+}
@@ -0,0 +1,50 @@
+use base64::{
+    engine::general_purpose::{STANDARD_NO_PAD, URL_SAFE_NO_PAD},
+    Engine,
+};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use std::io::{Read, Write};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+// Inputs in links are gzipped and base64url encoded, plain base64 text is
+// accepted too so short inputs can be written by hand.
+pub fn encode(input: &str) -> String {
+    let mut encoder = GzEncoder::new(vec![], Compression::best());
+    encoder.write_all(input.as_bytes()).unwrap();
+    URL_SAFE_NO_PAD.encode(encoder.finish().unwrap())
+}
+
+pub fn decode(param: &str) -> Option<String> {
+    let param = param.trim_end_matches('=');
+    let bytes = URL_SAFE_NO_PAD
+        .decode(param)
+        .or_else(|_| STANDARD_NO_PAD.decode(param))
+        .ok()?;
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut input = String::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_string(&mut input)
+            .ok()?;
+        Some(input)
+    } else {
+        String::from_utf8(bytes).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n";
+        let encoded = encode(input);
+        assert!(encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(decode(&encoded).as_deref(), Some(input));
+        assert_eq!(decode("MTAwMAoyMDAwCg==").as_deref(), Some("1000\n2000\n"));
+        assert_eq!(decode("not base64!"), None);
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_2022_solutions::{Parameters, Registry, SolveError, Timed};
+use bevy::prelude::*;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    bevy::tasks::{AsyncComputeTaskPool, Task},
+    futures_lite::future,
+};
+#[cfg(target_arch = "wasm32")]
+use {
+    js_sys::Array,
+    std::{cell::RefCell, time::Duration},
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    web_sys::{MessageEvent, Worker},
+};
+
+#[cfg(target_arch = "wasm32")]
+const WORKER_URL: &str = "./aoc_2022_worker_loader.js";
+
+pub type SolveResult = Result<Timed, SolveError>;
+
+// Dropping the task cancels the solve. Natively a solver that already started
+// keeps its pool thread until it returns and the answer is discarded, on wasm
+// the worker running it is terminated.
+#[derive(Component)]
+pub struct SolveTask {
+    #[cfg(not(target_arch = "wasm32"))]
+    task: Task<SolveResult>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SolveTask {
+    pub fn spawn(registry: Arc<Registry>, day: usize, input: String, params: Parameters) -> Self {
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            let solver = registry.get(day).unwrap();
+            solver.solve_timed(&input, &params)
+        });
+        Self { task }
+    }
+
+    pub fn poll(&mut self) -> Option<SolveResult> {
+        future::block_on(future::poll_once(&mut self.task))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static WORKER: RefCell<Option<(Worker, Closure<dyn FnMut(MessageEvent)>)>> = RefCell::new(None);
+    static RESULT: RefCell<Option<SolveResult>> = RefCell::new(None);
+}
+
+#[cfg(target_arch = "wasm32")]
+impl SolveTask {
+    pub fn spawn(_registry: Arc<Registry>, day: usize, input: String, params: Parameters) -> Self {
+        let worker = match Worker::new(WORKER_URL) {
+            Ok(worker) => worker,
+            Err(_) => {
+                let err = SolveError::invalid("could not start the solver worker");
+                RESULT.with(|result| *result.borrow_mut() = Some(Err(err)));
+                return Self {};
+            }
+        };
+        RESULT.with(|result| *result.borrow_mut() = None);
+        let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(|event: MessageEvent| {
+            let response = decode(&event.data());
+            RESULT.with(|result| *result.borrow_mut() = Some(response));
+        });
+        worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        let request = Array::of2(&JsValue::from(day as u32), &JsValue::from_str(&input));
+        for (name, value) in params.iter() {
+            request.push(&JsValue::from_str(name));
+            request.push(&JsValue::from(value as f64));
+        }
+        worker.post_message(&request).ok();
+        WORKER.with(|current| *current.borrow_mut() = Some((worker, onmessage)));
+        Self {}
+    }
+
+    pub fn poll(&mut self) -> Option<SolveResult> {
+        RESULT.with(|result| result.borrow_mut().take())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for SolveTask {
+    fn drop(&mut self) {
+        WORKER.with(|current| {
+            if let Some((worker, _)) = current.borrow_mut().take() {
+                worker.terminate();
+            }
+        });
+    }
+}
+
+// Mirrors the reply built in `worker/src/main.rs`: `[false, message]` when
+// parsing fails, otherwise `[true, parse seconds, ok, answer or message,
+// seconds, ...]` with three values per part.
+#[cfg(target_arch = "wasm32")]
+fn decode(data: &JsValue) -> SolveResult {
+    let reply = Array::from(data);
+    if !reply.get(0).is_truthy() {
+        return Err(SolveError::invalid(
+            reply.get(1).as_string().unwrap_or_default(),
+        ));
+    }
+    let seconds = |value: JsValue| Duration::from_secs_f64(value.as_f64().unwrap_or_default());
+    let parts = (2..reply.length())
+        .step_by(3)
+        .map(|idx| {
+            let text = reply.get(idx + 1).as_string().unwrap_or_default();
+            let result = if reply.get(idx).is_truthy() {
+                Ok(text)
+            } else {
+                Err(SolveError::invalid(text))
+            };
+            (result, seconds(reply.get(idx + 2)))
+        })
+        .collect();
+    Ok(Timed {
+        parse: seconds(reply.get(1)),
+        parts,
+    })
+}
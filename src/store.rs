@@ -0,0 +1,214 @@
+use aoc_2022_solutions::{Parameters, Timed};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::Duration};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, path::PathBuf};
+#[cfg(target_arch = "wasm32")]
+use web_sys::Storage;
+
+#[cfg(not(target_arch = "wasm32"))]
+const FILE_NAME: &str = "store.json";
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "aoc2022-store";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayRecord {
+    pub input: String,
+    #[serde(default)]
+    pub params: BTreeMap<String, i64>,
+    pub parse: Duration,
+    pub parts: Vec<(String, Duration)>,
+}
+
+impl DayRecord {
+    // Only runs where every part succeeded are kept.
+    pub fn new(input: String, params: &Parameters, timed: &Timed) -> Option<Self> {
+        let parts = timed
+            .parts
+            .iter()
+            .map(|(result, elapsed)| Some((result.clone().ok()?, *elapsed)))
+            .collect::<Option<_>>()?;
+        Some(Self {
+            input,
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+            parse: timed.parse,
+            parts,
+        })
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        let mut params = Parameters::default();
+        for (name, value) in &self.params {
+            params.set(name, *value);
+        }
+        params
+    }
+
+    pub fn timed(&self) -> Timed {
+        Timed {
+            parse: self.parse,
+            parts: self
+                .parts
+                .iter()
+                .map(|(answer, elapsed)| (Ok(answer.clone()), *elapsed))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Unknown,
+    Correct,
+    Wrong(String),
+}
+
+// The last successful input and answers of each day together with the answers
+// the user had accepted, kept in the user's data dir natively and in
+// `localStorage` on wasm.
+#[derive(Resource, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Store {
+    #[serde(default)]
+    days: BTreeMap<usize, DayRecord>,
+    #[serde(default)]
+    accepted: BTreeMap<usize, BTreeMap<usize, String>>,
+}
+
+impl Store {
+    pub fn load() -> Self {
+        read()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, day: usize) -> Option<&DayRecord> {
+        self.days.get(&day)
+    }
+
+    pub fn insert(&mut self, day: usize, record: DayRecord) {
+        self.days.insert(day, record);
+    }
+
+    pub fn accept(&mut self, day: usize, part: usize, answer: String) {
+        self.accepted.entry(day).or_default().insert(part, answer);
+    }
+
+    pub fn verify(&self, day: usize, part: usize, answer: &str) -> Verdict {
+        match self.accepted.get(&day).and_then(|parts| parts.get(&part)) {
+            None => Verdict::Unknown,
+            Some(accepted) if accepted == answer => Verdict::Correct,
+            Some(accepted) => Verdict::Wrong(accepted.clone()),
+        }
+    }
+
+    pub fn stars(&self, day: usize) -> usize {
+        let timed = match self.get(day) {
+            Some(record) => record.timed(),
+            None => return 0,
+        };
+        (1..=timed.parts.len())
+            .filter(|&part| self.verify(day, part, timed.answer(part).unwrap()) == Verdict::Correct)
+            .count()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => write(&json),
+            Err(err) => warn!("could not save results: {}", err),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("aoc_2022").join(FILE_NAME))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read() -> Option<String> {
+    fs::read_to_string(path()?).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write(json: &str) {
+    let path = match path() {
+        Some(path) => path,
+        None => return,
+    };
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, json));
+    if let Err(err) = result {
+        warn!("could not save results to {}: {}", path.display(), err);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read() -> Option<String> {
+    storage()?.get_item(STORAGE_KEY).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write(json: &str) {
+    let saved = storage().and_then(|storage| storage.set_item(STORAGE_KEY, json).ok());
+    if saved.is_none() {
+        warn!("could not save results to localStorage");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2022_solutions::SolveError;
+
+    #[test]
+    fn round_trip() {
+        let timed = Timed {
+            parse: Duration::from_micros(12),
+            parts: vec![
+                (Ok("24000".to_owned()), Duration::from_micros(3)),
+                (Ok("45000".to_owned()), Duration::from_millis(4)),
+            ],
+        };
+        let mut params = Parameters::default();
+        params.set("half_grid", 10);
+        let mut store = Store::default();
+        let record = DayRecord::new("1000\n\n2000\n".to_owned(), &params, &timed);
+        store.insert(1, record.unwrap());
+        let mut failed = timed.clone();
+        failed.parts[1].0 = Err(SolveError::invalid("no elves"));
+        assert_eq!(DayRecord::new(String::new(), &params, &failed), None);
+        assert_eq!(store.get(1).unwrap().timed(), timed);
+        assert_eq!(store.get(1).unwrap().parameters(), params);
+        assert_eq!(store.get(2), None);
+
+        assert_eq!(store.verify(1, 1, "24000"), Verdict::Unknown);
+        assert_eq!(store.stars(1), 0);
+        store.accept(1, 1, "24000".to_owned());
+        store.accept(1, 2, "45001".to_owned());
+        assert_eq!(store.verify(1, 1, "24000"), Verdict::Correct);
+        assert_eq!(
+            store.verify(1, 2, "45000"),
+            Verdict::Wrong("45001".to_owned())
+        );
+        assert_eq!(store.stars(1), 1);
+        store.accept(1, 2, "45000".to_owned());
+        assert_eq!(store.stars(1), 2);
+        assert_eq!(store.stars(2), 0);
+
+        let json = serde_json::to_string(&store).unwrap();
+        assert!(json.starts_with("{\"days\":{\"1\":{\"input\":\"1000\\n\\n2000\\n\""));
+        assert!(json.ends_with("\"accepted\":{\"1\":{\"1\":\"24000\",\"2\":\"45000\"}}}"));
+        assert_eq!(serde_json::from_str::<Store>(&json).unwrap(), store);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{DaySelectState, GameState};
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContext};
+
+mod day09;
+mod day12;
+mod day14;
+mod day17;
+
+const SPEEDS: std::ops::RangeInclusive<f32> = 1.0..=100000.0;
+// Frames longer than this only count this long, so a stall doesn't make the
+// next frame run thousands of steps.
+const MAX_FRAME: f32 = 0.1;
+// Room kept clear for the controls at the top of the screen.
+const TOP_BAR: f32 = 60.0;
+
+pub struct VisualizePlugin;
+
+impl Plugin for VisualizePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Playback::new(Scene::None.speed()))
+            .add_state(Scene::None)
+            .add_system_set(SystemSet::on_enter(GameState::Visualize).with_system(visualize_setup))
+            .add_system_set(SystemSet::on_update(GameState::Visualize).with_system(controls_system))
+            .add_system_set(SystemSet::on_exit(GameState::Visualize).with_system(visualize_cleanup))
+            .add_plugin(day09::RopePlugin)
+            .add_plugin(day12::HillPlugin)
+            .add_plugin(day14::SandFallPlugin)
+            .add_plugin(day17::RockFallPlugin);
+    }
+}
+
+// One state per visualization, so each sets itself up and cleans up after
+// itself like the screens of a day.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum Scene {
+    None,
+    Rope,
+    Hill,
+    SandFall,
+    RockFall,
+}
+
+impl Scene {
+    pub fn of(day: usize) -> Option<Self> {
+        match day {
+            9 => Some(Self::Rope),
+            12 => Some(Self::Hill),
+            14 => Some(Self::SandFall),
+            17 => Some(Self::RockFall),
+            _ => None,
+        }
+    }
+
+    // Steps per second to start with, fast enough to see progress when a
+    // single step is as small as a grain of sand moving one tile.
+    fn speed(&self) -> f32 {
+        match self {
+            Self::SandFall => 500.0,
+            _ => 20.0,
+        }
+    }
+}
+
+// The controls shared by every scene. Scenes ask `steps` how far to advance
+// each frame and describe where they are in `status`.
+#[derive(Resource)]
+pub struct Playback {
+    pub playing: bool,
+    // Steps per second.
+    pub speed: f32,
+    pub status: String,
+    // Set by a scene when its simulation can't go any further.
+    pub finished: bool,
+    single: bool,
+    due: f32,
+}
+
+impl Playback {
+    fn new(speed: f32) -> Self {
+        Self {
+            playing: true,
+            speed,
+            status: String::new(),
+            finished: false,
+            single: false,
+            due: 0.0,
+        }
+    }
+
+    pub fn steps(&mut self, delta: f32) -> usize {
+        if self.finished {
+            return 0;
+        }
+        if std::mem::take(&mut self.single) {
+            return 1;
+        }
+        if !self.playing {
+            return 0;
+        }
+        self.due += delta.min(MAX_FRAME) * self.speed;
+        let steps = self.due as usize;
+        self.due -= steps as f32;
+        steps
+    }
+
+    fn step_once(&mut self) {
+        self.playing = false;
+        self.single = true;
+    }
+}
+
+// Where to center a grid `size` tiles large, and how many pixels to draw each
+// tile with, so it fits the window below the controls.
+pub fn fit(window: &Window, size: Vec2) -> (Vec2, f32) {
+    let scale = (window.width() / size.x)
+        .min((window.height() - TOP_BAR) / size.y)
+        .max(0.1);
+    (Vec2::new(0.0, -TOP_BAR / 2.0), scale)
+}
+
+fn visualize_setup(
+    mut playback: ResMut<Playback>,
+    mut scene: ResMut<State<Scene>>,
+    day_select_state: Res<State<DaySelectState>>,
+) {
+    if let Some(next) = Scene::of(day_select_state.current().0) {
+        *playback = Playback::new(next.speed());
+        scene.set(next).ok();
+    }
+}
+
+fn visualize_cleanup(
+    mut scene: ResMut<State<Scene>>,
+    mut cameras: Query<&mut Transform, With<Camera2d>>,
+) {
+    scene.set(Scene::None).ok();
+    for mut transform in &mut cameras {
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+        transform.scale = Vec3::ONE;
+    }
+}
+
+// Space plays or pauses, the right arrow takes a single step, up and down
+// double or halve the speed, R restarts and Escape goes back to the day.
+fn controls_system(
+    mut egui_context: ResMut<EguiContext>,
+    mut keys: ResMut<Input<KeyCode>>,
+    mut playback: ResMut<Playback>,
+    mut game_state: ResMut<State<GameState>>,
+    mut scene: ResMut<State<Scene>>,
+) {
+    let mut back = false;
+    let mut restart = false;
+    if !egui_context.ctx_mut().wants_keyboard_input() {
+        // Escape is used up here, the day screen runs next and would otherwise
+        // take the same press as leaving the day.
+        back = keys.clear_just_pressed(KeyCode::Escape);
+        restart = keys.just_pressed(KeyCode::R);
+        if keys.just_pressed(KeyCode::Space) {
+            playback.playing = !playback.playing;
+        }
+        if keys.just_pressed(KeyCode::Right) {
+            playback.step_once();
+        }
+        if keys.just_pressed(KeyCode::Up) {
+            playback.speed = (playback.speed * 2.0).min(*SPEEDS.end());
+        }
+        if keys.just_pressed(KeyCode::Down) {
+            playback.speed = (playback.speed / 2.0).max(*SPEEDS.start());
+        }
+    }
+    egui::TopBottomPanel::top("visualize").show(egui_context.ctx_mut(), |ui| {
+        ui.horizontal(|ui| {
+            back |= ui.button("Back").clicked();
+            let label = if playback.playing { "Pause" } else { "Play" };
+            if ui.button(label).clicked() {
+                playback.playing = !playback.playing;
+            }
+            if ui.button("Step").clicked() {
+                playback.step_once();
+            }
+            restart |= ui.button("Restart").clicked();
+            ui.add(
+                egui::Slider::new(&mut playback.speed, SPEEDS)
+                    .logarithmic(true)
+                    .text("steps/s"),
+            );
+        });
+        ui.label(&playback.status);
+    });
+
+    if restart && *scene.current() != Scene::None {
+        playback.finished = false;
+        scene.restart().ok();
+    }
+    if back {
+        game_state.set(GameState::Day).ok();
+    }
+}
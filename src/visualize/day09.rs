@@ -0,0 +1,255 @@
+use super::{Playback, Scene};
+use crate::{days::InputState, despawn_screen};
+use aoc_2022_solutions::day09::Rope;
+use bevy::{prelude::*, utils::HashMap};
+use bevy_egui::{egui, EguiContext};
+
+const CELL: f32 = 12.0;
+const HEAD: Color = Color::rgb(0.9, 0.3, 0.25);
+const KNOT: Color = Color::rgb(0.95, 0.85, 0.6);
+const TAIL: Color = Color::rgb(0.55, 0.8, 1.0);
+const TRAIL: Color = Color::rgb(0.4, 0.7, 1.0);
+// A visited cell fades over this many seconds from when the tail was last on
+// it, down to a faint mark that stays.
+const FADE: f32 = 3.0;
+const FADED: f32 = 0.15;
+const KNOTS: std::ops::RangeInclusive<usize> = 1..=50;
+
+pub struct RopePlugin;
+
+impl Plugin for RopePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RopeOptions>()
+            .add_system_set(SystemSet::on_enter(Scene::Rope).with_system(rope_setup))
+            .add_system_set(
+                SystemSet::on_update(Scene::Rope)
+                    .with_system(rope_system)
+                    .with_system(trail_system.after(rope_system))
+                    .with_system(camera_system.after(rope_system))
+                    .with_system(options_system),
+            )
+            .add_system_set(
+                SystemSet::on_exit(Scene::Rope)
+                    .with_system(despawn_screen::<OnRopeScene>)
+                    .with_system(rope_cleanup),
+            );
+    }
+}
+
+// Kept across restarts, which is how a new knot count takes effect.
+#[derive(Resource)]
+struct RopeOptions {
+    knots: usize,
+}
+
+impl Default for RopeOptions {
+    fn default() -> Self {
+        Self { knots: 10 }
+    }
+}
+
+#[derive(Resource)]
+struct Bridge {
+    rope: Rope,
+    // The sprite of every cell the tail visited.
+    trail: HashMap<(isize, isize), Entity>,
+}
+
+#[derive(Component)]
+struct OnRopeScene;
+
+#[derive(Component)]
+struct Knot(usize);
+
+// When the tail was last on the cell, in seconds since startup.
+#[derive(Component)]
+struct Trail(f32);
+
+fn position((x, y): (isize, isize), z: f32) -> Vec3 {
+    Vec3::new(x as f32 * CELL, y as f32 * CELL, z)
+}
+
+fn rope_setup(
+    mut commands: Commands,
+    mut playback: ResMut<Playback>,
+    input_state: Res<State<InputState>>,
+    options: Res<RopeOptions>,
+) {
+    let rope = match Rope::new(&input_state.current().0, options.knots) {
+        Ok(rope) => rope,
+        Err(err) => {
+            playback.status = err.to_string();
+            playback.finished = true;
+            return;
+        }
+    };
+    let last = options.knots - 1;
+    // Knots nearer the head are drawn on top, so the head shows when knots
+    // overlap.
+    for (index, knot) in rope.knots().enumerate() {
+        let color = match index {
+            0 => HEAD,
+            _ if index == last => TAIL,
+            _ => KNOT,
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(CELL * 0.8)),
+                    ..default()
+                },
+                transform: Transform::from_translation(position(
+                    knot,
+                    2.0 + (last - index) as f32 / options.knots as f32,
+                )),
+                ..default()
+            },
+            Knot(index),
+            OnRopeScene,
+        ));
+    }
+    playback.status = status(&rope);
+    commands.insert_resource(Bridge {
+        rope,
+        trail: HashMap::default(),
+    });
+}
+
+fn rope_cleanup(mut commands: Commands) {
+    commands.remove_resource::<Bridge>();
+}
+
+fn status(rope: &Rope) -> String {
+    let instruction = match rope.instruction() {
+        Some((index, (direction, amount))) => format!(
+            "move {} of {}: {} {}",
+            index + 1,
+            rope.instructions(),
+            direction,
+            amount
+        ),
+        None => "done".to_owned(),
+    };
+    format!(
+        "{} | the tail visited {} positions",
+        instruction,
+        rope.visited()
+    )
+}
+
+// Moves the head as many steps as the playback asks for, leaving a trail
+// sprite on every cell the tail steps on.
+fn rope_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    bridge: Option<ResMut<Bridge>>,
+    mut playback: ResMut<Playback>,
+    mut trails: Query<&mut Trail>,
+    mut knots: Query<(&Knot, &mut Transform)>,
+) {
+    let mut bridge = match bridge {
+        Some(bridge) => bridge,
+        None => return,
+    };
+    let steps = playback.steps(time.delta_seconds());
+    if steps == 0 {
+        return;
+    }
+    let now = time.elapsed_seconds();
+    for _ in 0..steps {
+        if !bridge.rope.step() {
+            playback.finished = true;
+            break;
+        }
+        let tail = bridge.rope.tail();
+        match bridge.trail.get(&tail) {
+            Some(entity) => {
+                if let Ok(mut trail) = trails.get_mut(*entity) {
+                    trail.0 = now;
+                }
+            }
+            None => {
+                let entity = commands
+                    .spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: TRAIL,
+                                custom_size: Some(Vec2::splat(CELL)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position(tail, 1.0)),
+                            ..default()
+                        },
+                        Trail(now),
+                        OnRopeScene,
+                    ))
+                    .id();
+                bridge.trail.insert(tail, entity);
+            }
+        }
+    }
+    let positions: Vec<_> = bridge.rope.knots().collect();
+    for (knot, mut transform) in &mut knots {
+        let z = transform.translation.z;
+        transform.translation = position(positions[knot.0], z);
+    }
+    playback.status = status(&bridge.rope);
+}
+
+fn trail_system(time: Res<Time>, mut trails: Query<(&Trail, &mut Sprite)>) {
+    let now = time.elapsed_seconds();
+    for (trail, mut sprite) in &mut trails {
+        let alpha = (1.0 - (now - trail.0) / FADE).max(FADED);
+        sprite.color.set_a(alpha);
+    }
+}
+
+// Follows the head, sliding smoothly behind it.
+fn camera_system(
+    time: Res<Time>,
+    knots: Query<(&Knot, &Transform), Without<Camera2d>>,
+    mut cameras: Query<&mut Transform, With<Camera2d>>,
+) {
+    let head = match knots.iter().find(|(knot, _)| knot.0 == 0) {
+        Some((_, head)) => head.translation.truncate(),
+        None => return,
+    };
+    for mut transform in &mut cameras {
+        let camera = transform.translation.truncate();
+        let moved = camera + (head - camera) * (time.delta_seconds() * 3.0).min(1.0);
+        transform.translation = moved.extend(transform.translation.z);
+    }
+}
+
+// Minus and equals (or plus) take a knot off or add one.
+fn options_system(
+    mut egui_context: ResMut<EguiContext>,
+    keys: Res<Input<KeyCode>>,
+    mut options: ResMut<RopeOptions>,
+    mut playback: ResMut<Playback>,
+    mut scene: ResMut<State<Scene>>,
+) {
+    let mut knots = options.knots;
+    if !egui_context.ctx_mut().wants_keyboard_input() {
+        if keys.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+            knots = knots.saturating_sub(1).max(*KNOTS.start());
+        }
+        if keys.any_just_pressed([KeyCode::Equals, KeyCode::Plus, KeyCode::NumpadAdd]) {
+            knots = (knots + 1).min(*KNOTS.end());
+        }
+    }
+    egui::Window::new("Rope")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.add(egui::Slider::new(&mut knots, KNOTS).text("knots"));
+            ui.weak("part 1 uses 2 knots, part 2 uses 10");
+        });
+    if knots != options.knots {
+        options.knots = knots;
+        playback.finished = false;
+        scene.restart().ok();
+    }
+}
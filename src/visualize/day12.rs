@@ -0,0 +1,302 @@
+use super::{fit, Playback, Scene};
+use crate::{days::InputState, despawn_screen};
+use aoc_2022_solutions::day12::PathSearch;
+use bevy::{
+    prelude::*,
+    render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+use bevy_egui::{egui, EguiContext};
+
+// The terrain goes from green valleys over brown slopes up to snowy peaks.
+const LOW: [f32; 3] = [40.0, 100.0, 55.0];
+const SLOPE: [f32; 3] = [150.0, 120.0, 75.0];
+const PEAK: [f32; 3] = [235.0, 235.0, 240.0];
+const VISITED: [f32; 3] = [50.0, 110.0, 220.0];
+const FRONTIER: [u8; 4] = [130, 230, 255, 255];
+const PATH: [u8; 4] = [240, 70, 50, 255];
+const START: [u8; 4] = [255, 255, 255, 255];
+const END: [u8; 4] = [255, 200, 0, 255];
+
+type Square = (usize, usize);
+
+pub struct HillPlugin;
+
+impl Plugin for HillPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HillOptions>()
+            .add_system_set(SystemSet::on_enter(Scene::Hill).with_system(hill_setup))
+            .add_system_set(
+                SystemSet::on_update(Scene::Hill)
+                    .with_system(search_system)
+                    .with_system(layout_system)
+                    .with_system(click_system)
+                    .with_system(options_system),
+            )
+            .add_system_set(
+                SystemSet::on_exit(Scene::Hill)
+                    .with_system(despawn_screen::<OnHillScene>)
+                    .with_system(hill_cleanup),
+            );
+    }
+}
+
+// Kept across restarts, which is how clicking a new start or end takes effect.
+// Without any, the search climbs from "S" to "E" like part 1.
+#[derive(Resource, Default)]
+struct HillOptions {
+    ends: Option<(Square, Square)>,
+}
+
+// The search and the texture the heightmap is drawn on, one pixel per square.
+#[derive(Resource)]
+struct Hill {
+    search: PathSearch,
+    image: Handle<Image>,
+    size: Vec2,
+    waves: usize,
+}
+
+impl Hill {
+    fn paint(&self, image: &mut Image, (x, y): Square, color: [u8; 4]) {
+        let index = (y * self.size.x as usize + x) * 4;
+        image.data[index..index + 4].copy_from_slice(&color);
+    }
+
+    fn terrain(&self, square: Square) -> [f32; 3] {
+        let height = self.search.elevation(square).unwrap_or_default() as f32 / 25.0;
+        if height < 0.5 {
+            mix(LOW, SLOPE, height * 2.0)
+        } else {
+            mix(SLOPE, PEAK, height * 2.0 - 1.0)
+        }
+    }
+
+    // The frontier, the shortest path once there is one and the ends, over
+    // whatever the squares were painted with before.
+    fn paint_search(&self, image: &mut Image) {
+        for square in self.search.frontier() {
+            self.paint(image, *square, FRONTIER);
+        }
+        for square in self.search.path().unwrap_or_default() {
+            self.paint(image, square, PATH);
+        }
+        self.paint(image, self.search.source(), START);
+        if let Some(end) = self.search.target() {
+            self.paint(image, end, END);
+        }
+    }
+}
+
+fn mix(from: [f32; 3], to: [f32; 3], amount: f32) -> [f32; 3] {
+    [0, 1, 2].map(|i| from[i] + (to[i] - from[i]) * amount)
+}
+
+fn rgba(color: [f32; 3]) -> [u8; 4] {
+    [color[0] as u8, color[1] as u8, color[2] as u8, 255]
+}
+
+#[derive(Component)]
+struct OnHillScene;
+
+fn hill_setup(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut playback: ResMut<Playback>,
+    input_state: Res<State<InputState>>,
+    options: Res<HillOptions>,
+) {
+    let mut search = match PathSearch::new(&input_state.current().0) {
+        Ok(search) => search,
+        Err(err) => {
+            playback.status = err.to_string();
+            playback.finished = true;
+            return;
+        }
+    };
+    // Squares picked on another input may be off this map, which leaves the
+    // search between "S" and "E".
+    if let Some((start, end)) = options.ends {
+        search.reroute(start, end);
+    }
+    let (width, height) = search.size();
+    let size = Vec2::new(width as f32, height as f32);
+
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: width as u32,
+            height: height as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    image.sampler_descriptor = ImageSampler::nearest();
+    let mut hill = Hill {
+        search,
+        image: Handle::default(),
+        size,
+        waves: 0,
+    };
+    for y in 0..height {
+        for x in 0..width {
+            hill.paint(&mut image, (x, y), rgba(hill.terrain((x, y))));
+        }
+    }
+    hill.paint_search(&mut image);
+    hill.image = images.add(image);
+
+    commands.spawn((
+        SpriteBundle {
+            texture: hill.image.clone(),
+            ..default()
+        },
+        OnHillScene,
+    ));
+    playback.status = status(&hill);
+    playback.finished = hill.search.finished();
+    commands.insert_resource(hill);
+}
+
+fn hill_cleanup(mut commands: Commands) {
+    commands.remove_resource::<Hill>();
+}
+
+fn status(hill: &Hill) -> String {
+    let search = &hill.search;
+    let progress = match search.path() {
+        Some(path) => format!("shortest path: {} steps", path.len() - 1),
+        None if search.finished() => "no path".to_owned(),
+        None => format!("{} squares in the frontier", search.frontier().len()),
+    };
+    format!("wave {} | {}", hill.waves, progress)
+}
+
+// Runs as many waves as the playback asks for, tinting the squares the search
+// leaves behind.
+fn search_system(
+    time: Res<Time>,
+    hill: Option<ResMut<Hill>>,
+    mut images: ResMut<Assets<Image>>,
+    mut playback: ResMut<Playback>,
+) {
+    let mut hill = match hill {
+        Some(hill) => hill,
+        None => return,
+    };
+    let steps = playback.steps(time.delta_seconds());
+    if steps == 0 {
+        return;
+    }
+    let image = images.get_mut(&hill.image).unwrap();
+    for _ in 0..steps {
+        if hill.search.finished() {
+            break;
+        }
+        for square in hill.search.frontier() {
+            hill.paint(
+                image,
+                *square,
+                rgba(mix(hill.terrain(*square), VISITED, 0.5)),
+            );
+        }
+        hill.search.step();
+        hill.waves += 1;
+    }
+    hill.paint_search(image);
+    playback.finished = hill.search.finished();
+    playback.status = status(&hill);
+}
+
+// Scales the heightmap to fit the window below the controls.
+fn layout_system(
+    windows: Res<Windows>,
+    hill: Option<Res<Hill>>,
+    mut sprites: Query<(&mut Transform, &mut Sprite), With<OnHillScene>>,
+) {
+    let hill = match hill {
+        Some(hill) => hill,
+        None => return,
+    };
+    let (center, scale) = fit(windows.primary(), hill.size);
+    for (mut transform, mut sprite) in &mut sprites {
+        transform.translation = center.extend(0.0);
+        sprite.custom_size = Some(hill.size * scale);
+    }
+}
+
+// A left click moves the start to the square under the cursor and a right
+// click moves the end there, then the search starts over.
+fn click_system(
+    mut egui_context: ResMut<EguiContext>,
+    windows: Res<Windows>,
+    buttons: Res<Input<MouseButton>>,
+    hill: Option<Res<Hill>>,
+    mut options: ResMut<HillOptions>,
+    mut playback: ResMut<Playback>,
+    mut scene: ResMut<State<Scene>>,
+) {
+    let hill = match hill {
+        Some(hill) => hill,
+        None => return,
+    };
+    let left = buttons.just_pressed(MouseButton::Left);
+    if !left && !buttons.just_pressed(MouseButton::Right)
+        || egui_context.ctx_mut().is_pointer_over_area()
+    {
+        return;
+    }
+    let window = windows.primary();
+    let cursor = match window.cursor_position() {
+        Some(cursor) => cursor,
+        None => return,
+    };
+    // The cursor counts up from the bottom of the window, squares count down
+    // from the top of the map.
+    let (center, scale) = fit(window, hill.size);
+    let offset = (cursor - Vec2::new(window.width(), window.height()) / 2.0 - center) / scale;
+    let (x, y) = (offset.x + hill.size.x / 2.0, hill.size.y / 2.0 - offset.y);
+    if x < 0.0 || y < 0.0 || x >= hill.size.x || y >= hill.size.y {
+        return;
+    }
+    let square = (x as usize, y as usize);
+    let start = hill.search.source();
+    let end = hill.search.target().unwrap_or(square);
+    options.ends = Some(if left { (square, end) } else { (start, square) });
+    playback.finished = false;
+    scene.restart().ok();
+}
+
+fn options_system(
+    mut egui_context: ResMut<EguiContext>,
+    hill: Option<Res<Hill>>,
+    mut options: ResMut<HillOptions>,
+    mut playback: ResMut<Playback>,
+    mut scene: ResMut<State<Scene>>,
+) {
+    let mut reset = false;
+    egui::Window::new("Hill")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            if let Some(hill) = &hill {
+                ui.label(format!("start: {:?}", hill.search.source()));
+                if let Some(end) = hill.search.target() {
+                    ui.label(format!("end: {:?}", end));
+                }
+            }
+            ui.weak("left click moves the start, right click moves the end");
+            reset = ui
+                .add_enabled(options.ends.is_some(), egui::Button::new("Back to S and E"))
+                .clicked();
+        });
+    if reset {
+        options.ends = None;
+        playback.finished = false;
+        scene.restart().ok();
+    }
+}
@@ -0,0 +1,271 @@
+use super::{fit, Playback, Scene};
+use crate::{days::InputState, despawn_screen};
+use aoc_2022_solutions::day14::{SandFall, Step};
+use bevy::{
+    prelude::*,
+    render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+use bevy_egui::{egui, EguiContext};
+
+const AIR: [u8; 4] = [19, 19, 19, 255];
+const ROCK: [u8; 4] = [110, 110, 110, 255];
+const SAND: [u8; 4] = [230, 190, 90, 255];
+const GRAIN: Color = Color::rgb(1.0, 0.95, 0.6);
+
+pub struct SandFallPlugin;
+
+impl Plugin for SandFallPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SandOptions>()
+            .add_system_set(SystemSet::on_enter(Scene::SandFall).with_system(sand_fall_setup))
+            .add_system_set(
+                SystemSet::on_update(Scene::SandFall)
+                    .with_system(sand_fall_system)
+                    .with_system(layout_system.after(sand_fall_system))
+                    .with_system(options_system),
+            )
+            .add_system_set(
+                SystemSet::on_exit(Scene::SandFall)
+                    .with_system(despawn_screen::<OnSandFallScene>)
+                    .with_system(sand_fall_cleanup),
+            );
+    }
+}
+
+// Kept across restarts, which is how switching between the void and the floor
+// takes effect.
+#[derive(Resource, Default)]
+struct SandOptions {
+    floor: bool,
+}
+
+// The simulation and the texture the cave is drawn on, one pixel per tile
+// from `left`, `top`.
+#[derive(Resource)]
+struct Cave {
+    fall: SandFall,
+    image: Handle<Image>,
+    left: isize,
+    top: isize,
+    size: Vec2,
+    grain: (isize, isize),
+}
+
+impl Cave {
+    fn paint(&self, image: &mut Image, (x, y): (isize, isize), color: [u8; 4]) {
+        let (column, row) = (x - self.left, y - self.top);
+        if column < 0 || row < 0 || column >= self.size.x as isize || row >= self.size.y as isize {
+            return;
+        }
+        let index = (row as usize * self.size.x as usize + column as usize) * 4;
+        image.data[index..index + 4].copy_from_slice(&color);
+    }
+}
+
+#[derive(Component)]
+struct OnSandFallScene;
+
+#[derive(Component)]
+struct CaveImage;
+
+#[derive(Component)]
+struct Grain;
+
+fn sand_fall_setup(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut playback: ResMut<Playback>,
+    input_state: Res<State<InputState>>,
+    options: Res<SandOptions>,
+) {
+    let fall = match SandFall::new(&input_state.current().0, options.floor) {
+        Ok(fall) => fall,
+        Err(err) => {
+            playback.status = err.to_string();
+            playback.finished = true;
+            return;
+        }
+    };
+    // Without a floor the sand can't get past the outermost rocks, with one it
+    // piles up in a triangle as wide as it is high.
+    let depth = fall.max_depth();
+    let (mut left, mut right) = fall.blocked().fold((500, 500), |(left, right), (x, _)| {
+        (left.min(x), right.max(x))
+    });
+    let bottom = if options.floor {
+        left = left.min(500 - depth - 2);
+        right = right.max(500 + depth + 2);
+        depth + 2
+    } else {
+        depth + 1
+    };
+    let (left, top) = (left - 1, 0);
+    let size = Vec2::new((right + 2 - left) as f32, (bottom + 1 - top) as f32);
+
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x as u32,
+            height: size.y as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &AIR,
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    image.sampler_descriptor = ImageSampler::nearest();
+    let mut cave = Cave {
+        fall,
+        image: Handle::default(),
+        left,
+        top,
+        size,
+        grain: (500, 0),
+    };
+    for pos in cave.fall.blocked() {
+        cave.paint(&mut image, pos, ROCK);
+    }
+    if options.floor {
+        for x in left..left + size.x as isize {
+            cave.paint(&mut image, (x, bottom), ROCK);
+        }
+    }
+    cave.image = images.add(image);
+
+    commands.spawn((
+        SpriteBundle {
+            texture: cave.image.clone(),
+            ..default()
+        },
+        CaveImage,
+        OnSandFallScene,
+    ));
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: GRAIN,
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, 1.0),
+            ..default()
+        },
+        Grain,
+        OnSandFallScene,
+    ));
+    playback.status = status(&cave.fall);
+    commands.insert_resource(cave);
+}
+
+fn sand_fall_cleanup(mut commands: Commands) {
+    commands.remove_resource::<Cave>();
+}
+
+fn status(fall: &SandFall) -> String {
+    let mut status = format!("{} units of sand at rest", fall.rested());
+    if fall.done() {
+        status.push_str(" | done");
+    }
+    status
+}
+
+// Moves the grain as many steps as the playback asks for, painting it into
+// the texture once it comes to rest.
+fn sand_fall_system(
+    time: Res<Time>,
+    cave: Option<ResMut<Cave>>,
+    mut images: ResMut<Assets<Image>>,
+    mut playback: ResMut<Playback>,
+) {
+    let mut cave = match cave {
+        Some(cave) => cave,
+        None => return,
+    };
+    let steps = playback.steps(time.delta_seconds());
+    if steps == 0 {
+        return;
+    }
+    let mut rested = vec![];
+    for _ in 0..steps {
+        match cave.fall.step() {
+            Step::Fall(x, y) => cave.grain = (x, y),
+            Step::Rest(x, y) => {
+                rested.push((x, y));
+                cave.grain = (500, 0);
+            }
+            Step::Done => {
+                playback.finished = true;
+                break;
+            }
+        }
+    }
+    if !rested.is_empty() {
+        let image = images.get_mut(&cave.image).unwrap();
+        for pos in rested {
+            cave.paint(image, pos, SAND);
+        }
+    }
+    playback.status = status(&cave.fall);
+}
+
+// Scales the cave to fit the window below the controls, and puts the falling
+// grain on top of it.
+#[allow(clippy::type_complexity)]
+fn layout_system(
+    windows: Res<Windows>,
+    cave: Option<Res<Cave>>,
+    mut images: Query<(&mut Transform, &mut Sprite), (With<CaveImage>, Without<Grain>)>,
+    mut grains: Query<(&mut Transform, &mut Sprite, &mut Visibility), With<Grain>>,
+) {
+    let cave = match cave {
+        Some(cave) => cave,
+        None => return,
+    };
+    let (center, scale) = fit(windows.primary(), cave.size);
+    for (mut transform, mut sprite) in &mut images {
+        transform.translation = center.extend(0.0);
+        sprite.custom_size = Some(cave.size * scale);
+    }
+    let (x, y) = cave.grain;
+    let tile = Vec2::new(
+        (x - cave.left) as f32 + 0.5 - cave.size.x / 2.0,
+        cave.size.y / 2.0 - (y - cave.top) as f32 - 0.5,
+    );
+    for (mut transform, mut sprite, mut visibility) in &mut grains {
+        transform.translation = (center + tile * scale).extend(1.0);
+        sprite.custom_size = Some(Vec2::splat(scale));
+        visibility.is_visible = !cave.fall.done();
+    }
+}
+
+// F switches between the void of part 1 and the floor of part 2.
+fn options_system(
+    mut egui_context: ResMut<EguiContext>,
+    keys: Res<Input<KeyCode>>,
+    cave: Option<Res<Cave>>,
+    mut options: ResMut<SandOptions>,
+    mut playback: ResMut<Playback>,
+    mut scene: ResMut<State<Scene>>,
+) {
+    let mut floor = options.floor;
+    if !egui_context.ctx_mut().wants_keyboard_input() && keys.just_pressed(KeyCode::F) {
+        floor = !floor;
+    }
+    egui::Window::new("Sand")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            if let Some(cave) = &cave {
+                ui.heading(format!("{} units of sand", cave.fall.rested()));
+            }
+            ui.radio_value(&mut floor, false, "Void (part 1)");
+            ui.radio_value(&mut floor, true, "Floor (part 2)");
+        });
+    if floor != options.floor {
+        options.floor = floor;
+        playback.finished = false;
+        scene.restart().ok();
+    }
+}
@@ -0,0 +1,231 @@
+use super::{Playback, Scene};
+use crate::{days::InputState, despawn_screen};
+use aoc_2022_solutions::day17::{Cycle, RockFall, Step};
+use bevy::prelude::*;
+
+const CELL: f32 = 16.0;
+const WALL: Color = Color::rgb(0.35, 0.35, 0.35);
+const ROCK: Color = Color::rgb(0.7, 0.55, 0.3);
+const FALLING: Color = Color::rgb(1.0, 0.8, 0.3);
+const CYCLE: Color = Color::rgb(0.4, 0.9, 0.4);
+const CYCLE_BAND: Color = Color::rgba(0.4, 0.9, 0.4, 0.15);
+// Rows shown above the tower, enough for a new rock to appear in view.
+const HEADROOM: f32 = 12.0;
+// Settled rocks this far below the top can't be seen any more and are
+// despawned, so an endless run doesn't pile up sprites.
+const KEPT_ROWS: i64 = 300;
+
+pub struct RockFallPlugin;
+
+impl Plugin for RockFallPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(Scene::RockFall).with_system(rock_fall_setup))
+            .add_system_set(
+                SystemSet::on_update(Scene::RockFall)
+                    .with_system(rock_fall_system)
+                    .with_system(camera_system.after(rock_fall_system)),
+            )
+            .add_system_set(
+                SystemSet::on_exit(Scene::RockFall)
+                    .with_system(despawn_screen::<OnRockFallScene>)
+                    .with_system(rock_fall_cleanup),
+            );
+    }
+}
+
+#[derive(Resource)]
+struct Chamber(RockFall);
+
+#[derive(Component)]
+struct OnRockFallScene;
+
+#[derive(Component)]
+struct Settled(i64);
+
+#[derive(Component)]
+struct Falling;
+
+// The walls reach past the screen and follow the camera up.
+#[derive(Component)]
+struct Wall;
+
+fn cell(x: i64, y: i64, color: Color) -> SpriteBundle {
+    SpriteBundle {
+        sprite: Sprite {
+            color,
+            custom_size: Some(Vec2::splat(CELL - 1.0)),
+            ..default()
+        },
+        transform: Transform::from_xyz((x as f32 - 4.0) * CELL, y as f32 * CELL, 1.0),
+        ..default()
+    }
+}
+
+fn rock_fall_setup(
+    mut commands: Commands,
+    input_state: Res<State<InputState>>,
+    mut playback: ResMut<Playback>,
+) {
+    let fall = match RockFall::new(&input_state.current().0) {
+        Ok(fall) => fall,
+        Err(err) => {
+            playback.status = err.to_string();
+            playback.finished = true;
+            return;
+        }
+    };
+    for x in [0, 8] {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: WALL,
+                    custom_size: Some(Vec2::new(CELL, 1.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz((x as f32 - 4.0) * CELL, 0.0, 0.0),
+                ..default()
+            },
+            Wall,
+            OnRockFallScene,
+        ));
+    }
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: WALL,
+                custom_size: Some(Vec2::new(9.0 * CELL, CELL)),
+                ..default()
+            },
+            ..default()
+        },
+        OnRockFallScene,
+    ));
+    for (x, y) in fall.rock() {
+        commands.spawn((cell(x, y, FALLING), Falling, OnRockFallScene));
+    }
+    playback.status = status(&fall);
+    commands.insert_resource(Chamber(fall));
+}
+
+fn repeats(cycle: Option<Cycle>, row: i64) -> bool {
+    cycle.map_or(false, |cycle| {
+        (cycle.top - cycle.height + 1..=cycle.top).contains(&row)
+    })
+}
+
+fn rock_fall_cleanup(mut commands: Commands) {
+    commands.remove_resource::<Chamber>();
+}
+
+fn status(fall: &RockFall) -> String {
+    let mut status = format!("{} rocks, tower {} rows high", fall.rocks(), fall.top());
+    if let Some(cycle) = fall.cycle() {
+        status.push_str(&format!(
+            " | every {} rocks add {} rows, the highlighted rows {} to {} repeat",
+            cycle.rocks,
+            cycle.height,
+            cycle.top - cycle.height + 1,
+            cycle.top
+        ));
+    }
+    status
+}
+
+// Moves the rock as many steps as the playback asks for. The repeating rows
+// light up, and the playback pauses, as soon as the cycle detector of part 2
+// finds them.
+fn rock_fall_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    chamber: Option<ResMut<Chamber>>,
+    mut playback: ResMut<Playback>,
+    falling: Query<Entity, With<Falling>>,
+    mut settled: Query<(Entity, &Settled, &mut Sprite)>,
+) {
+    let mut chamber = match chamber {
+        Some(chamber) => chamber,
+        None => return,
+    };
+    let steps = playback.steps(time.delta_seconds());
+    if steps == 0 {
+        return;
+    }
+    let had_cycle = chamber.0.cycle().is_some();
+    for _ in 0..steps {
+        if let Step::Settle(rock) = chamber.0.step() {
+            let cycle = chamber.0.cycle();
+            for (x, y) in rock {
+                let color = if repeats(cycle, y) { CYCLE } else { ROCK };
+                commands.spawn((cell(x, y, color), Settled(y), OnRockFallScene));
+            }
+            if !had_cycle && chamber.0.cycle().is_some() {
+                break;
+            }
+        }
+    }
+
+    for entity in &falling {
+        commands.entity(entity).despawn();
+    }
+    for (x, y) in chamber.0.rock() {
+        commands.spawn((cell(x, y, FALLING), Falling, OnRockFallScene));
+    }
+    let top = chamber.0.top();
+    for (entity, row, _) in &settled {
+        if row.0 < top - KEPT_ROWS {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    if let (false, Some(cycle)) = (had_cycle, chamber.0.cycle()) {
+        for (_, row, mut sprite) in &mut settled {
+            if repeats(Some(cycle), row.0) {
+                sprite.color = CYCLE;
+            }
+        }
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: CYCLE_BAND,
+                    custom_size: Some(Vec2::new(7.0 * CELL, cycle.height as f32 * CELL)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(
+                    0.0,
+                    (cycle.top as f32 - (cycle.height as f32 - 1.0) / 2.0) * CELL,
+                    2.0,
+                ),
+                ..default()
+            },
+            OnRockFallScene,
+        ));
+        playback.playing = false;
+    }
+    playback.status = status(&chamber.0);
+}
+
+// Keeps the top of the tower in view, sliding up smoothly as it grows, and
+// stretches the walls over the whole screen.
+fn camera_system(
+    time: Res<Time>,
+    windows: Res<Windows>,
+    chamber: Option<Res<Chamber>>,
+    mut cameras: Query<&mut Transform, (With<Camera2d>, Without<Wall>)>,
+    mut walls: Query<(&mut Transform, &mut Sprite), With<Wall>>,
+) {
+    let chamber = match chamber {
+        Some(chamber) => chamber,
+        None => return,
+    };
+    let height = windows.primary().height();
+    let target =
+        ((chamber.0.top() as f32 + HEADROOM) * CELL - height / 2.0).max(height / 2.0 - CELL);
+    for mut transform in &mut cameras {
+        let y = &mut transform.translation.y;
+        *y += (target - *y) * (time.delta_seconds() * 5.0).min(1.0);
+        for (mut wall, mut sprite) in &mut walls {
+            wall.translation.y = *y;
+            sprite.custom_size = Some(Vec2::new(CELL, height));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::cell::RefCell;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{DragEvent, Event, File, FileReader, HtmlInputElement};
+
+thread_local! {
+    static LOADED: RefCell<Option<String>> = RefCell::new(None);
+}
+
+pub fn listen() {
+    let document = web_sys::window().unwrap().document().unwrap();
+
+    if let Some(picker) = document.get_element_by_id("aoc2022-file") {
+        let picker = picker.dyn_into::<HtmlInputElement>().unwrap();
+        let target = picker.clone();
+        let onchange = Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+            if let Some(file) = target.files().and_then(|files| files.get(0)) {
+                read(&file);
+            }
+            target.set_value("");
+        });
+        picker.set_onchange(Some(onchange.as_ref().unchecked_ref()));
+        onchange.forget();
+    }
+
+    let ondragover =
+        Closure::<dyn FnMut(DragEvent)>::new(|event: DragEvent| event.prevent_default());
+    document.set_ondragover(Some(ondragover.as_ref().unchecked_ref()));
+    ondragover.forget();
+
+    let ondrop = Closure::<dyn FnMut(DragEvent)>::new(|event: DragEvent| {
+        event.prevent_default();
+        let file = event
+            .data_transfer()
+            .and_then(|data| data.files())
+            .and_then(|files| files.get(0));
+        if let Some(file) = file {
+            read(&file);
+        }
+    });
+    document.set_ondrop(Some(ondrop.as_ref().unchecked_ref()));
+    ondrop.forget();
+}
+
+pub fn take() -> Option<String> {
+    LOADED.with(|loaded| loaded.borrow_mut().take())
+}
+
+fn read(file: &File) {
+    let reader = FileReader::new().unwrap();
+    let result = reader.clone();
+    let onload = Closure::once_into_js(move || {
+        if let Some(text) = result.result().ok().and_then(|text| text.as_string()) {
+            LOADED.with(|loaded| *loaded.borrow_mut() = Some(text));
+        }
+    });
+    reader.set_onload(Some(onload.unchecked_ref()));
+    reader.read_as_text(file).ok();
+}
@@ -0,0 +1,49 @@
+use crate::share;
+use wasm_bindgen::JsValue;
+use web_sys::UrlSearchParams;
+
+pub struct Route {
+    pub day: Option<usize>,
+    pub input: Option<String>,
+}
+
+// Reads `?day=12&input=...`, or the same after a `#` for hosts that drop the
+// query string.
+pub fn read() -> Route {
+    let location = web_sys::window().unwrap().location();
+    let query = location
+        .search()
+        .ok()
+        .filter(|search| !search.is_empty())
+        .or_else(|| location.hash().ok())
+        .unwrap_or_default();
+    let params = UrlSearchParams::new_with_str(query.trim_start_matches(['?', '#'])).unwrap();
+    Route {
+        day: params.get("day").and_then(|day| day.parse().ok()),
+        input: params.get("input").and_then(|input| share::decode(&input)),
+    }
+}
+
+pub fn write(day: Option<usize>) {
+    let window = web_sys::window().unwrap();
+    let url = match day {
+        Some(day) => format!("?day={}", day),
+        None => window.location().pathname().unwrap_or_default(),
+    };
+    if let Ok(history) = window.history() {
+        history
+            .replace_state_with_url(&JsValue::NULL, "", Some(&url))
+            .ok();
+    }
+}
+
+pub fn link(day: usize, input: &str) -> String {
+    let location = web_sys::window().unwrap().location();
+    format!(
+        "{}{}?day={}&input={}",
+        location.origin().unwrap_or_default(),
+        location.pathname().unwrap_or_default(),
+        day,
+        share::encode(input)
+    )
+}
@@ -0,0 +1,61 @@
+fn main() {
+    #[cfg(target_arch = "wasm32")]
+    worker::listen();
+}
+
+#[cfg(target_arch = "wasm32")]
+mod worker {
+    use aoc_2022_solutions::{Parameters, Registry, SolveError};
+    use js_sys::Array;
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use web_sys::{DedicatedWorkerGlobalScope, MessageEvent};
+
+    pub fn listen() {
+        let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+        let registry = Registry::default();
+        let reply_scope = scope.clone();
+        let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let request = Array::from(&event.data());
+            let day = request.get(0).as_f64().unwrap_or_default() as usize;
+            let input = request.get(1).as_string().unwrap_or_default();
+            let mut params = Parameters::default();
+            for idx in (2..request.length()).step_by(2) {
+                let name = request.get(idx).as_string().unwrap_or_default();
+                let value = request.get(idx + 1).as_f64().unwrap_or_default();
+                params.set(&name, value as i64);
+            }
+            reply_scope
+                .post_message(&solve(&registry, day, &input, &params))
+                .ok();
+        });
+        scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        onmessage.forget();
+    }
+
+    // The request is `[day, input, name, value, name, value, ...]` and the reply
+    // is decoded by `src/solving.rs` in the app.
+    fn solve(registry: &Registry, day: usize, input: &str, params: &Parameters) -> Array {
+        let reply = Array::new();
+        let result = registry
+            .get(day)
+            .ok_or_else(|| SolveError::invalid(format!("day {} has no solver", day)))
+            .and_then(|solver| solver.solve_timed(input, params));
+        match result {
+            Ok(timed) => {
+                reply.push(&JsValue::TRUE);
+                reply.push(&JsValue::from(timed.parse.as_secs_f64()));
+                for (result, elapsed) in timed.parts {
+                    reply.push(&JsValue::from_bool(result.is_ok()));
+                    let text = result.unwrap_or_else(|err| err.to_string());
+                    reply.push(&JsValue::from_str(&text));
+                    reply.push(&JsValue::from(elapsed.as_secs_f64()));
+                }
+            }
+            Err(err) => {
+                reply.push(&JsValue::FALSE);
+                reply.push(&JsValue::from_str(&err.to_string()));
+            }
+        }
+        reply
+    }
+}